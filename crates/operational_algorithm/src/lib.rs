@@ -0,0 +1,3 @@
+pub mod operational_options;
+pub mod operational_solution;
+pub mod operational_solver;
@@ -0,0 +1,65 @@
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use schedule_hypergraph::schedule_graph::FinishTime;
+use schedule_hypergraph::schedule_graph::StartTime;
+
+/// Configuration of the events that the `OperationalSolver` inserts into
+/// the day of a `Technician` next to the wrench time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OperationalOptions
+{
+    toolbox_duration: TimeDelta,
+    break_window: (StartTime, FinishTime),
+    permit_to_work_duration: TimeDelta,
+}
+
+impl OperationalOptions
+{
+    /// The toolbox talk is held at the start of every shift
+    pub fn with_toolbox_duration(mut self, toolbox_duration: TimeDelta) -> Self
+    {
+        self.toolbox_duration = toolbox_duration;
+        self
+    }
+
+    /// The mandatory break is taken at the same time by every `Technician`
+    pub fn with_break_window(mut self, break_window: (StartTime, FinishTime)) -> Self
+    {
+        self.break_window = break_window;
+        self
+    }
+
+    /// The waiting time for the permit to work before every `Activity`
+    pub fn with_permit_to_work_duration(mut self, permit_to_work_duration: TimeDelta) -> Self
+    {
+        self.permit_to_work_duration = permit_to_work_duration;
+        self
+    }
+
+    pub fn toolbox_duration(&self) -> TimeDelta
+    {
+        self.toolbox_duration
+    }
+
+    pub fn break_window(&self) -> (StartTime, FinishTime)
+    {
+        self.break_window
+    }
+
+    pub fn permit_to_work_duration(&self) -> TimeDelta
+    {
+        self.permit_to_work_duration
+    }
+}
+
+impl Default for OperationalOptions
+{
+    fn default() -> Self
+    {
+        Self {
+            toolbox_duration: TimeDelta::minutes(15),
+            break_window: (NaiveTime::from_hms_opt(11, 0, 0).unwrap(), NaiveTime::from_hms_opt(11, 30, 0).unwrap()),
+            permit_to_work_duration: TimeDelta::minutes(15),
+        }
+    }
+}
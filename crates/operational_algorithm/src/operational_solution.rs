@@ -0,0 +1,143 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use schedule_hypergraph::operational_instance::OperationalActivity;
+use schedule_hypergraph::operational_instance::OperationalInstance;
+use schedule_hypergraph::schedule_graph::FinishTime;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use schedule_hypergraph::schedule_graph::StartTime;
+use schedule_hypergraph::schedule_graph::TechnicianId;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationalEventType
+{
+    Toolbox,
+    Break,
+    PermitToWork,
+    WrenchTime
+    {
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    },
+    /// A locked assignment that the `OperationalSolver` planned around
+    Fixed
+    {
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationalEvent
+{
+    pub event_type: OperationalEventType,
+    pub time_window: (StartTime, FinishTime),
+}
+
+/// The sequence of events in every `Day` of a `Technician`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct OperationalSolution
+{
+    pub technician_id: TechnicianId,
+    /// The events of each `Day` ordered by their start.
+    pub operational_events: BTreeMap<NaiveDate, Vec<OperationalEvent>>,
+    /// The `Activity`s that did not fit into the shift of their `Day`. They
+    /// keep their old time window.
+    pub unsequenced_activities: Vec<(NaiveDate, OperationalActivity)>,
+}
+
+impl OperationalSolution
+{
+    /// The time window of the wrench time of the `Activity` on the `day`
+    pub fn activity_time_window(
+        &self,
+        day: NaiveDate,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Option<(StartTime, FinishTime)>
+    {
+        self.operational_events.get(&day)?.iter().find_map(|operational_event| {
+            (operational_event.event_type
+                == OperationalEventType::WrenchTime {
+                    work_order_number,
+                    activity_number,
+                })
+            .then_some(operational_event.time_window)
+        })
+    }
+
+    /// Writes the wrench time of every sequenced `Activity` into the
+    /// `EdgeType::Assign` hyperedges of the `Technician`. Only the
+    /// assignments whose time window changed are touched.
+    ///
+    /// Nothing is written when one of the assignments of the
+    /// `OperationalInstance` is no longer in the `ScheduleGraph`.
+    pub fn apply(
+        &self,
+        schedule_graph: &mut ScheduleGraph,
+        operational_instance: &OperationalInstance,
+    ) -> Result<AppliedOperationalSolution, ScheduleGraphErrors>
+    {
+        let mut applied_operational_solution = AppliedOperationalSolution::default();
+        for (day, operational_day) in &operational_instance.operational_days {
+            for operational_activity in &operational_day.activities {
+                let Some(to) = self.activity_time_window(*day, operational_activity.work_order_number, operational_activity.activity_number) else {
+                    continue;
+                };
+
+                let from = schedule_graph.technician_assignment_time(
+                    self.technician_id,
+                    &operational_activity.work_order_number,
+                    operational_activity.activity_number,
+                    *day,
+                )?;
+                if from != Some(operational_activity.time_window) {
+                    return Err(ScheduleGraphErrors::AssignmentMissing);
+                }
+                if operational_activity.time_window == to {
+                    continue;
+                }
+
+                applied_operational_solution.retimed.push(ActivityRetime {
+                    day: *day,
+                    work_order_number: operational_activity.work_order_number,
+                    activity_number: operational_activity.activity_number,
+                    from: operational_activity.time_window,
+                    to,
+                });
+            }
+        }
+
+        for activity_retime in &applied_operational_solution.retimed {
+            schedule_graph.retime_assignment_activity(
+                self.technician_id,
+                &activity_retime.work_order_number,
+                activity_retime.activity_number,
+                activity_retime.day,
+                activity_retime.to,
+            )?;
+        }
+        Ok(applied_operational_solution)
+    }
+}
+
+/// Report of `OperationalSolution::apply`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AppliedOperationalSolution
+{
+    /// The assignments that got a new time window, ordered by `Day` and start.
+    pub retimed: Vec<ActivityRetime>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityRetime
+{
+    pub day: NaiveDate,
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub from: (StartTime, FinishTime),
+    pub to: (StartTime, FinishTime),
+}
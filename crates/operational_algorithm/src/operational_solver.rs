@@ -0,0 +1,348 @@
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use schedule_hypergraph::operational_instance::OperationalDay;
+use schedule_hypergraph::operational_instance::OperationalInstance;
+use schedule_hypergraph::schedule_graph::FinishTime;
+use schedule_hypergraph::schedule_graph::StartTime;
+
+use crate::operational_options::OperationalOptions;
+use crate::operational_solution::OperationalEvent;
+use crate::operational_solution::OperationalEventType;
+use crate::operational_solution::OperationalSolution;
+
+/// Sequences the assigned `Activity`s of a `Technician` inside of the shift
+/// of every `Day`.
+///
+/// Every shift starts with the toolbox talk and no `Activity` of a `Day` is
+/// sequenced when the toolbox talk does not fit in its shift. The `Activity`s
+/// keep the order of their current start and each one is preceded by the
+/// waiting time for its permit to work. An `Activity` is never split, so when
+/// it does not fit before the break or a fixed event it is moved behind it.
+///
+/// The hard constraints are:
+/// * Every event lies inside of the shift of the `Day`.
+/// * No event overlaps the break or the fixed events of the `Day`.
+/// * The wrench time of an `Activity` is as long as its current time window so
+///   the booked `Work` does not change.
+#[derive(Debug)]
+pub struct OperationalSolver<'a>
+{
+    operational_instance: &'a OperationalInstance,
+    operational_options: OperationalOptions,
+}
+
+impl<'a> OperationalSolver<'a>
+{
+    pub fn new(operational_instance: &'a OperationalInstance) -> Self
+    {
+        Self {
+            operational_instance,
+            operational_options: OperationalOptions::default(),
+        }
+    }
+
+    pub fn with_operational_options(mut self, operational_options: OperationalOptions) -> Self
+    {
+        self.operational_options = operational_options;
+        self
+    }
+
+    pub fn solve(&self) -> OperationalSolution
+    {
+        let mut operational_solution = OperationalSolution {
+            technician_id: self.operational_instance.technician_id,
+            ..OperationalSolution::default()
+        };
+
+        for (day, operational_day) in &self.operational_instance.operational_days {
+            let Some(shift) = operational_day.shift else {
+                operational_solution.unsequenced_activities.extend(
+                    operational_day
+                        .activities
+                        .iter()
+                        .map(|operational_activity| (*day, operational_activity.clone())),
+                );
+                continue;
+            };
+
+            let mut day_sequence = DaySequence::new(shift, operational_day, &self.operational_options);
+            if !day_sequence.insert(OperationalEventType::Toolbox, self.operational_options.toolbox_duration()) {
+                operational_solution.unsequenced_activities.extend(
+                    operational_day
+                        .activities
+                        .iter()
+                        .map(|operational_activity| (*day, operational_activity.clone())),
+                );
+                operational_solution.operational_events.insert(*day, day_sequence.events());
+                continue;
+            }
+            for operational_activity in &operational_day.activities {
+                let (start_time, finish_time) = operational_activity.time_window;
+                let wrench_time = OperationalEventType::WrenchTime {
+                    work_order_number: operational_activity.work_order_number,
+                    activity_number: operational_activity.activity_number,
+                };
+                if !day_sequence.insert_after_permit(wrench_time, finish_time - start_time, self.operational_options.permit_to_work_duration()) {
+                    operational_solution.unsequenced_activities.push((*day, operational_activity.clone()));
+                }
+            }
+
+            operational_solution.operational_events.insert(*day, day_sequence.events());
+        }
+        operational_solution
+    }
+}
+
+/// The events of a single `Day` measured as the time since midnight, so
+/// that the arithmetic can not wrap around.
+struct DaySequence
+{
+    shift: (TimeDelta, TimeDelta),
+    blocked: Vec<(TimeDelta, TimeDelta, OperationalEventType)>,
+    sequenced: Vec<(TimeDelta, TimeDelta, OperationalEventType)>,
+    cursor: TimeDelta,
+}
+
+impl DaySequence
+{
+    fn new(shift: (StartTime, FinishTime), operational_day: &OperationalDay, operational_options: &OperationalOptions) -> Self
+    {
+        let shift = (since_midnight(shift.0), since_midnight(shift.1));
+
+        let (break_start, break_finish) = operational_options.break_window();
+        let mut blocked = vec![(since_midnight(break_start), since_midnight(break_finish), OperationalEventType::Break)];
+        blocked.retain(|(start, finish, _)| shift.0 < *finish && *start < shift.1);
+        for fixed_event in &operational_day.fixed_events {
+            let (start_time, finish_time) = fixed_event.time_window;
+            let fixed = OperationalEventType::Fixed {
+                work_order_number: fixed_event.work_order_number,
+                activity_number: fixed_event.activity_number,
+            };
+            blocked.push((since_midnight(start_time), since_midnight(finish_time), fixed));
+        }
+        blocked.sort_by_key(|(start, finish, _)| (*start, *finish));
+
+        Self {
+            cursor: shift.0,
+            shift,
+            blocked,
+            sequenced: vec![],
+        }
+    }
+
+    /// The earliest start from the cursor where `duration` fits between the
+    /// blocked events and before the end of the shift.
+    fn earliest_start(&self, duration: TimeDelta) -> Option<TimeDelta>
+    {
+        let mut start = self.cursor;
+        for (blocked_start, blocked_finish, _) in &self.blocked {
+            if start < *blocked_finish && *blocked_start < start + duration {
+                start = *blocked_finish;
+            }
+        }
+        (start + duration <= self.shift.1).then_some(start)
+    }
+
+    fn insert(&mut self, event_type: OperationalEventType, duration: TimeDelta) -> bool
+    {
+        let Some(start) = self.earliest_start(duration) else {
+            return false;
+        };
+        self.sequenced.push((start, start + duration, event_type));
+        self.cursor = start + duration;
+        true
+    }
+
+    /// The permit to work and the wrench time are inserted back to back.
+    fn insert_after_permit(&mut self, event_type: OperationalEventType, duration: TimeDelta, permit_to_work_duration: TimeDelta) -> bool
+    {
+        let Some(start) = self.earliest_start(permit_to_work_duration + duration) else {
+            return false;
+        };
+        self.sequenced
+            .push((start, start + permit_to_work_duration, OperationalEventType::PermitToWork));
+        self.sequenced
+            .push((start + permit_to_work_duration, start + permit_to_work_duration + duration, event_type));
+        self.cursor = start + permit_to_work_duration + duration;
+        true
+    }
+
+    fn events(mut self) -> Vec<OperationalEvent>
+    {
+        self.sequenced.extend(self.blocked);
+        self.sequenced.sort_by_key(|(start, finish, _)| (*start, *finish));
+        self.sequenced
+            .into_iter()
+            .map(|(start, finish, event_type)| OperationalEvent {
+                event_type,
+                time_window: (NaiveTime::MIN + start, NaiveTime::MIN + finish),
+            })
+            .collect()
+    }
+}
+
+fn since_midnight(time: NaiveTime) -> TimeDelta
+{
+    time - NaiveTime::MIN
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use schedule_hypergraph::operational_instance::OperationalActivity;
+    use schedule_hypergraph::operational_instance::OperationalDay;
+    use schedule_hypergraph::operational_instance::OperationalInstance;
+
+    use super::OperationalSolver;
+    use crate::operational_options::OperationalOptions;
+    use crate::operational_solution::OperationalEvent;
+    use crate::operational_solution::OperationalEventType;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime
+    {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn operational_activity(activity_number: u64, time_window: (NaiveTime, NaiveTime)) -> OperationalActivity
+    {
+        OperationalActivity {
+            work_order_number: 1122334455,
+            activity_number,
+            time_window,
+        }
+    }
+
+    fn operational_instance(operational_day: OperationalDay) -> OperationalInstance
+    {
+        OperationalInstance {
+            technician_id: 1001,
+            operational_days: BTreeMap::from([(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap(), operational_day)]),
+        }
+    }
+
+    #[test]
+    fn test_solve_inserts_toolbox_break_and_permits()
+    {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let operational_instance = operational_instance(OperationalDay {
+            shift: Some((time(7, 0), time(17, 0))),
+            available_hours: 8.0,
+            activities: vec![
+                operational_activity(10, (time(8, 0), time(10, 0))),
+                operational_activity(20, (time(10, 0), time(14, 0))),
+            ],
+            fixed_events: vec![],
+        });
+
+        let operational_solution = OperationalSolver::new(&operational_instance).solve();
+
+        let wrench_time = |activity_number| OperationalEventType::WrenchTime {
+            work_order_number: 1122334455,
+            activity_number,
+        };
+        let event = |event_type, start_time, finish_time| OperationalEvent {
+            event_type,
+            time_window: (start_time, finish_time),
+        };
+        // The second `Activity` does not fit before the break
+        assert_eq!(
+            operational_solution.operational_events[&day],
+            vec![
+                event(OperationalEventType::Toolbox, time(7, 0), time(7, 15)),
+                event(OperationalEventType::PermitToWork, time(7, 15), time(7, 30)),
+                event(wrench_time(10), time(7, 30), time(9, 30)),
+                event(OperationalEventType::Break, time(11, 0), time(11, 30)),
+                event(OperationalEventType::PermitToWork, time(11, 30), time(11, 45)),
+                event(wrench_time(20), time(11, 45), time(15, 45)),
+            ]
+        );
+        assert_eq!(
+            operational_solution.activity_time_window(day, 1122334455, 20),
+            Some((time(11, 45), time(15, 45)))
+        );
+        assert!(operational_solution.unsequenced_activities.is_empty());
+    }
+
+    #[test]
+    fn test_solve_plans_around_fixed_events()
+    {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let fixed_event = operational_activity(30, (time(7, 0), time(9, 0)));
+        let operational_instance = operational_instance(OperationalDay {
+            shift: Some((time(7, 0), time(15, 0))),
+            available_hours: 8.0,
+            activities: vec![
+                operational_activity(10, (time(9, 0), time(11, 0))),
+                operational_activity(20, (time(11, 0), time(15, 0))),
+            ],
+            fixed_events: vec![fixed_event.clone()],
+        });
+        let operational_options = OperationalOptions::default()
+            .with_break_window((time(12, 0), time(12, 30)))
+            .with_permit_to_work_duration(TimeDelta::zero());
+
+        let operational_solution = OperationalSolver::new(&operational_instance)
+            .with_operational_options(operational_options)
+            .solve();
+
+        // The toolbox talk waits for the fixed event and the second
+        // `Activity` does not fit behind the break.
+        let events = &operational_solution.operational_events[&day];
+        assert_eq!(events[0].time_window, (time(7, 0), time(9, 0)));
+        assert_eq!(events[1].event_type, OperationalEventType::Toolbox);
+        assert_eq!(events[1].time_window, (time(9, 0), time(9, 15)));
+        assert_eq!(
+            operational_solution.activity_time_window(day, 1122334455, 10),
+            Some((time(9, 15), time(11, 15)))
+        );
+        assert_eq!(
+            operational_solution.unsequenced_activities,
+            vec![(day, operational_activity(20, (time(11, 0), time(15, 0))))]
+        );
+    }
+    #[test]
+    fn test_solve_skips_the_day_without_toolbox()
+    {
+        let day = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let fixed_event = operational_activity(30, (time(7, 0), time(15, 0)));
+        let activities = vec![operational_activity(10, (time(9, 0), time(11, 0)))];
+        let operational_instance = operational_instance(OperationalDay {
+            shift: Some((time(7, 0), time(15, 0))),
+            available_hours: 8.0,
+            activities: activities.clone(),
+            fixed_events: vec![fixed_event],
+        });
+
+        let operational_solution = OperationalSolver::new(&operational_instance).solve();
+
+        // The fixed event fills the whole shift so the toolbox talk does not fit
+        assert_eq!(
+            operational_solution.operational_events[&day],
+            vec![
+                OperationalEvent {
+                    event_type: OperationalEventType::Fixed {
+                        work_order_number: 1122334455,
+                        activity_number: 30,
+                    },
+                    time_window: (time(7, 0), time(15, 0)),
+                },
+                OperationalEvent {
+                    event_type: OperationalEventType::Break,
+                    time_window: (time(11, 0), time(11, 30)),
+                },
+            ]
+        );
+        assert_eq!(
+            operational_solution.unsequenced_activities,
+            activities
+                .into_iter()
+                .map(|operational_activity| (day, operational_activity))
+                .collect::<Vec<_>>()
+        );
+    }
+}
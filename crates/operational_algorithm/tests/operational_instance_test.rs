@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use operational_algorithm::operational_solution::ActivityRetime;
+use operational_algorithm::operational_solver::OperationalSolver;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
+use scheduling_environment::system_clock::MockClock;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillCatalog;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::WorkOrder;
+
+#[test]
+fn test_apply_operational_solution()
+{
+    let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+    let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+    schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+    schedule_graph.add_period(period).unwrap();
+
+    let work_order = WorkOrder::new(
+        1122334455,
+        period.start_date(),
+        vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(4))],
+    )
+    .unwrap();
+    schedule_graph.add_work_order(&work_order).unwrap();
+
+    let day = period.start_date();
+    for technician_id in [1001, 1002] {
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(technician_id)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+    }
+
+    let time = |hour, minute| NaiveTime::from_hms_opt(hour, minute, 0).unwrap();
+    schedule_graph
+        .add_assignment_activity(vec![1001, 1002], 1122334455, 10, vec![day], (time(8, 0), time(12, 0)))
+        .unwrap();
+
+    let operational_instance = schedule_graph
+        .derive_operational_instance(1001, day, day, &WorkingHoursRule::default())
+        .unwrap();
+    let operational_solution = OperationalSolver::new(&operational_instance).solve();
+
+    let applied_operational_solution = operational_solution.apply(&mut schedule_graph, &operational_instance).unwrap();
+    assert_eq!(
+        applied_operational_solution.retimed,
+        vec![ActivityRetime {
+            day,
+            work_order_number: 1122334455,
+            activity_number: 10,
+            from: (time(8, 0), time(12, 0)),
+            to: (time(11, 45), time(15, 45)),
+        }]
+    );
+
+    // Only the assignment of the sequenced `Technician` moves
+    assert_eq!(
+        schedule_graph.technician_assignment_time(1001, &1122334455, 10, day),
+        Ok(Some((time(11, 45), time(15, 45))))
+    );
+    assert_eq!(
+        schedule_graph.technician_assignment_time(1002, &1122334455, 10, day),
+        Ok(Some((time(8, 0), time(12, 0))))
+    );
+
+    // The `OperationalInstance` is outdated after the assignment moved
+    assert_eq!(
+        operational_solution.apply(&mut schedule_graph, &operational_instance),
+        Err(ScheduleGraphErrors::AssignmentMissing)
+    );
+    let operational_instance = schedule_graph
+        .derive_operational_instance(1001, day, day, &WorkingHoursRule::default())
+        .unwrap();
+    let operational_solution = OperationalSolver::new(&operational_instance).solve();
+    assert!(
+        operational_solution
+            .apply(&mut schedule_graph, &operational_instance)
+            .unwrap()
+            .retimed
+            .is_empty()
+    );
+}
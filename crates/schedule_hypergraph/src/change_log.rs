@@ -0,0 +1,254 @@
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// Number of `GraphChange`s that the `ScheduleGraph` keeps at least for its
+/// `ChangeSubscription`s by default.
+const DEFAULT_CHANGE_LOG_CAPACITY: usize = 100_000;
+
+/// Returned by `ChangeSubscription::poll` to a subscription that fell behind
+/// the changes that are kept.
+static RESYNC_REQUIRED: [GraphChange; 1] = [GraphChange::ResyncRequired];
+
+/// A part of the `ScheduleGraph` that was changed. Every hyperedge that is
+/// added, removed or locked records a change for each of its nodes, so the
+/// derived instances can find out what they have to derive again.
+#[derive(Hash, Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
+pub enum GraphChange
+{
+    /// The `WorkOrder` was added, completed or one of its hyperedges changed.
+    WorkOrder(WorkOrderNumber),
+    Activity(WorkOrderNumber, ActivityNumber),
+    /// The `Technician` was added or one of its hyperedges changed.
+    Technician(TechnicianId),
+    /// A hyperedge on the `Day` changed, e.g. an availability or an
+    /// assignment.
+    Day(NaiveDate),
+    /// The `Period` was added to, frozen in or archived from the horizon.
+    /// Assignments and exclusions of a `Period` are recorded on the
+    /// `WorkOrder`.
+    Period(Period),
+    /// The changes since the last poll were dropped from the change log, so
+    /// everything has to be derived again.
+    ResyncRequired,
+}
+
+/// The last `GraphChange`s of the `ScheduleGraph` in the order they were
+/// made. The oldest changes are dropped once more than twice the `capacity`
+/// are recorded, so at least `capacity` changes are always kept.
+#[derive(Clone, Debug)]
+pub(crate) struct ChangeLog
+{
+    /// Number of changes that were dropped. The cursor of a
+    /// `ChangeSubscription` counts them as well.
+    offset: usize,
+    graph_changes: Vec<GraphChange>,
+    capacity: usize,
+}
+
+impl Default for ChangeLog
+{
+    fn default() -> Self
+    {
+        Self::with_capacity(DEFAULT_CHANGE_LOG_CAPACITY)
+    }
+}
+
+impl ChangeLog
+{
+    pub(crate) fn with_capacity(capacity: usize) -> Self
+    {
+        Self {
+            offset: 0,
+            graph_changes: vec![],
+            capacity: capacity.max(1),
+        }
+    }
+
+    /// The cursor right after the last change
+    fn end(&self) -> usize
+    {
+        self.offset + self.graph_changes.len()
+    }
+
+    fn extend(&mut self, graph_changes: impl IntoIterator<Item = GraphChange>)
+    {
+        self.graph_changes.extend(graph_changes);
+        if self.graph_changes.len() > 2 * self.capacity {
+            let dropped = self.graph_changes.len() - self.capacity;
+            self.graph_changes.drain(..dropped);
+            self.offset += dropped;
+        }
+    }
+}
+
+/// The position of a reader in the change log of the `ScheduleGraph`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ChangeSubscription
+{
+    cursor: usize,
+}
+
+impl ChangeSubscription
+{
+    /// The `GraphChange`s recorded since the last poll in the order they
+    /// were made. A change may be reported more than once. When some of the
+    /// changes were already dropped from the change log only
+    /// `GraphChange::ResyncRequired` is reported.
+    pub fn poll<'a>(&mut self, schedule_graph: &'a ScheduleGraph) -> &'a [GraphChange]
+    {
+        let change_log = schedule_graph.change_log();
+        let cursor = std::mem::replace(&mut self.cursor, change_log.end());
+        match cursor.checked_sub(change_log.offset) {
+            Some(start) => &change_log.graph_changes[start..],
+            None => &RESYNC_REQUIRED,
+        }
+    }
+}
+
+/// Public API to follow the changes of the graph.
+impl ScheduleGraph
+{
+    /// Subscribes to the changes made from now on.
+    pub fn subscribe(&self) -> ChangeSubscription
+    {
+        ChangeSubscription {
+            cursor: self.change_log().end(),
+        }
+    }
+
+    /// Keeps at least `capacity` `GraphChange`s for the `ChangeSubscription`s
+    /// instead of the default.
+    pub fn with_change_log_capacity(mut self, capacity: usize) -> Self
+    {
+        *self.change_log_mut() = ChangeLog::with_capacity(capacity);
+        self
+    }
+}
+
+/// Helper methods to record the changes
+impl ScheduleGraph
+{
+    /// Records a change for every node of a hyperedge. `Skill`s are left
+    /// out as they never change and `Period`s only change with the horizon.
+    pub(crate) fn record_hyperedge_changes(&mut self, node_indices: &[NodeIndex])
+    {
+        let graph_changes = node_indices
+            .iter()
+            .filter_map(|&node_index| match &self.nodes()[node_index] {
+                Node::Technician(technician_id) => Some(GraphChange::Technician(*technician_id)),
+                Node::WorkOrder(work_order_number) => Some(GraphChange::WorkOrder(*work_order_number)),
+                Node::Activity(activity) => Some(GraphChange::Activity(self.activity_work_order(node_index), activity.activity_number())),
+                Node::Day(day) => Some(GraphChange::Day(*day)),
+                Node::Period(_) | Node::Skill(_) => None,
+            })
+            .collect::<BTreeSet<_>>();
+
+        self.change_log_mut().extend(graph_changes);
+    }
+
+    pub(crate) fn record_change(&mut self, graph_change: GraphChange)
+    {
+        self.change_log_mut().extend([graph_change]);
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use super::GraphChange;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_change_subscription()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        let mut change_subscription = schedule_graph.subscribe();
+        schedule_graph.add_period(period).unwrap();
+        assert_eq!(change_subscription.poll(&schedule_graph), [GraphChange::Period(period)]);
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            period.start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let graph_changes = change_subscription.poll(&schedule_graph);
+        assert!(graph_changes.contains(&GraphChange::WorkOrder(1122334455)));
+        assert!(graph_changes.contains(&GraphChange::Activity(1122334455, 10)));
+        assert!(graph_changes.contains(&GraphChange::Day(period.start_date())));
+
+        // A new subscription only sees the changes made after it
+        let mut late_subscription = schedule_graph.subscribe();
+        schedule_graph
+            .add_assignment_activity_days(&1122334455, 10, vec![period.start_date()])
+            .unwrap();
+        let graph_changes = vec![GraphChange::Activity(1122334455, 10), GraphChange::Day(period.start_date())];
+        assert_eq!(late_subscription.poll(&schedule_graph), graph_changes);
+        assert_eq!(change_subscription.poll(&schedule_graph), graph_changes);
+        assert!(change_subscription.poll(&schedule_graph).is_empty());
+
+        // Freezing locks the hyperedges and the `Period` itself
+        schedule_graph.freeze_period(&period).unwrap();
+        let mut frozen_changes = graph_changes.clone();
+        frozen_changes.push(GraphChange::Period(period));
+        assert_eq!(change_subscription.poll(&schedule_graph), frozen_changes);
+
+        schedule_graph.complete_work_order(&1122334455).unwrap();
+        assert_eq!(change_subscription.poll(&schedule_graph), [GraphChange::WorkOrder(1122334455)]);
+    }
+
+    #[test]
+    fn test_change_log_capacity()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock).with_change_log_capacity(4);
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_period(period).unwrap();
+        let mut change_subscription = schedule_graph.subscribe();
+        let mut lagging_subscription = schedule_graph.subscribe();
+
+        // A subscription that keeps up sees every change
+        for work_order_number in 1122334450..1122334460 {
+            let work_order = WorkOrder::new(work_order_number, period.start_date(), vec![]).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+            assert!(
+                change_subscription
+                    .poll(&schedule_graph)
+                    .contains(&GraphChange::WorkOrder(work_order_number))
+            );
+            assert!(schedule_graph.change_log().graph_changes.len() <= 2 * 4);
+        }
+
+        // The lagging subscription missed dropped changes and has to derive
+        // everything again, after which it follows the changes as before.
+        assert_eq!(lagging_subscription.poll(&schedule_graph), [GraphChange::ResyncRequired]);
+        assert!(lagging_subscription.poll(&schedule_graph).is_empty());
+        schedule_graph.complete_work_order(&1122334455).unwrap();
+        assert_eq!(lagging_subscription.poll(&schedule_graph), [GraphChange::WorkOrder(1122334455)]);
+        assert_eq!(change_subscription.poll(&schedule_graph), [GraphChange::WorkOrder(1122334455)]);
+    }
+}
@@ -1,7 +1,33 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::ActivityRelation;
+use scheduling_environment::work_order::NumberOfPeople;
+use scheduling_environment::work_order::SkillRequirement;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderInfo;
 use scheduling_environment::work_order::WorkOrderNumber;
+use scheduling_environment::work_order::work_hours;
 
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::FinishTime;
+use crate::schedule_graph::LockState;
 use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
 use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::StartTime;
+use crate::schedule_graph::TechnicianId;
 
 /// This contains the API for deriving problem instances for the
 /// optimization algorithms.
@@ -9,42 +35,989 @@ use crate::schedule_graph::ScheduleGraph;
 /// Every methods has to be non-mutating
 impl ScheduleGraph
 {
-    pub fn derive_work_orders(&self, work_order_numbers: &[WorkOrderNumber]) -> GraphWorkOrders
+    /// Derives the `GraphWorkOrder` of every requested `WorkOrderNumber`.
+    /// The numbers that are not in the `ScheduleGraph` are reported in
+    /// `GraphWorkOrders::missing_work_orders`. Every other error means that
+    /// the `ScheduleGraph` is inconsistent and is returned.
+    pub fn derive_work_orders(&self, work_order_numbers: &[WorkOrderNumber]) -> Result<GraphWorkOrders, ScheduleGraphErrors>
+    {
+        let mut graph_work_orders = GraphWorkOrders::default();
+        for work_order_number in work_order_numbers {
+            match self.graph_work_order(work_order_number) {
+                Ok(graph_work_order) => {
+                    graph_work_orders.work_orders.insert(*work_order_number, graph_work_order);
+                }
+                Err(ScheduleGraphErrors::WorkOrderMissing) => graph_work_orders.missing_work_orders.push(*work_order_number),
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(graph_work_orders)
+    }
+
+    /// Every `Period` of the horizon in chronological order. Archived
+    /// `Period`s are left out.
+    pub fn periods(&self) -> Vec<Period>
+    {
+        let mut periods = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::Period(period) if !self.is_period_archived(period) => Some(*period),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        periods.sort();
+        periods
+    }
+
+    pub fn work_order_numbers(&self) -> Vec<WorkOrderNumber>
+    {
+        let mut work_order_numbers = self
+            .nodes()
+            .iter()
+            .filter_map(|node| match node {
+                Node::WorkOrder(work_order_number) => Some(*work_order_number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        work_order_numbers
+    }
+
+    /// Format
+    /// `vec![$work_order, $period, @days]`
+    pub fn work_order_exclusions(&self, work_order_number: &WorkOrderNumber) -> Result<HashSet<Period>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        Ok(self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::Exclude))
+            .filter_map(|node_index| match self.nodes()[node_index] {
+                Node::Period(period) => Some(period),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// The `Period` that the `WorkOrder` is assigned to by the strategic
+    /// plan. The assignments of `Technician`s to the `WorkOrder` are not
+    /// read.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]`
+    pub fn work_order_assigned_period(&self, work_order_number: &WorkOrderNumber) -> Result<Option<Period>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        Ok(self.incidence_list()[work_order_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Assign(None, _)))
+            .find_map(|hyperedge| match hyperedge.nodes() {
+                [_, period_node_index] => match self.nodes()[*period_node_index] {
+                    Node::Period(period) => Some(period),
+                    _ => None,
+                },
+                _ => None,
+            }))
+    }
+
+    /// The day linked to the `WorkOrder` by the `EdgeType::LatestFinish`
+    /// hyperedge, if the `WorkOrder` has a latest finish.
+    pub fn work_order_latest_finish(&self, work_order_number: &WorkOrderNumber) -> Result<Option<NaiveDate>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        Ok(self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::LatestFinish))
+            .find_map(|node_index| match self.nodes()[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            }))
+    }
+
+    /// The last `Period` that the `WorkOrder` may be scheduled in. This is the
+    /// `Period` of the latest finish, or the end of the horizon when the
+    /// `WorkOrder` does not have a latest finish. An overdue `WorkOrder`,
+    /// whose latest finish lies in an archived `Period`, is due in the first
+    /// `Period` of the horizon.
+    ///
+    /// Fails with `PeriodMissing` when the horizon has no `Period`.
+    pub fn work_order_latest_period(&self, work_order_number: &WorkOrderNumber) -> Result<Period, ScheduleGraphErrors>
+    {
+        let periods = self.periods();
+
+        match self.work_order_latest_finish(work_order_number)? {
+            Some(latest_finish) => periods.into_iter().find(|period| latest_finish <= period.end_date()),
+            None => periods.last().copied(),
+        }
+        .ok_or(ScheduleGraphErrors::PeriodMissing)
+    }
+
+    /// The `Period` that the `WorkOrder` is frozen in by a locked
+    /// assignment.
+    pub fn work_order_locked_period(&self, work_order_number: &WorkOrderNumber) -> Result<Option<Period>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        Ok(self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| {
+                matches!(edge_type, EdgeType::Assign(None, LockState::Locked))
+            })
+            .find_map(|node_index| match self.nodes()[node_index] {
+                Node::Period(period) => Some(period),
+                _ => None,
+            }))
+    }
+
+    /// Every `Period` of the horizon that is frozen by
+    /// `ScheduleGraph::freeze_period` or that holds a locked assignment of an
+    /// `Activity` on one of its days. The work inside of these `Period`s is
+    /// fixed and the strategic planning may not change them. A `WorkOrder`
+    /// frozen by `ScheduleGraph::freeze_work_order` does not lock its
+    /// `Period`.
+    pub fn locked_periods(&self) -> HashSet<Period>
+    {
+        let periods = self.periods();
+
+        self.hyperedges()
+            .iter()
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Assign(_, LockState::Locked)))
+            .flat_map(|hyperedge| hyperedge.nodes())
+            .filter_map(|&node_index| match self.nodes()[node_index] {
+                Node::Day(day) => periods.iter().find(|period| period.contains(day)).copied(),
+                _ => None,
+            })
+            .chain(
+                periods
+                    .iter()
+                    .copied()
+                    .filter(|period| self.horizon_state().frozen_periods.contains(period)),
+            )
+            .collect()
+    }
+
+    /// The days that the `Activity` is planned on by the tactical plan,
+    /// sorted by date.
+    ///
+    /// Format
+    /// `vec![$activity, @days]`
+    pub fn activity_planned_days(
+        &self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<Vec<NaiveDate>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        let mut days = self
+            .neighbors_by_edge_type(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Assign(None, _)))
+            .filter_map(|node_index| match self.nodes()[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        days.sort();
+        Ok(days)
+    }
+
+    /// The time window that the `Technician` works on the `Activity` on the
+    /// `day`, if they are assigned to it.
+    ///
+    /// Format
+    /// `vec![$activity, @technicians, @days]`
+    pub fn technician_assignment_time(
+        &self,
+        technician_id: TechnicianId,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+        day: NaiveDate,
+    ) -> Result<Option<(StartTime, FinishTime)>, ScheduleGraphErrors>
+    {
+        let technician_node_index = *self.technician_node_index(&technician_id)?;
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        Ok(self.incidence_list()[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| hyperedge.nodes().contains(&technician_node_index))
+            .filter(|hyperedge| hyperedge.nodes().iter().any(|&node_index| self.nodes()[node_index] == Node::Day(day)))
+            .find_map(|hyperedge| match hyperedge.edge_type() {
+                EdgeType::Assign(start_and_finish_time, _) => *start_and_finish_time,
+                _ => None,
+            }))
+    }
+
+    /// Whether the days of the `Activity` are locked by a frozen `Period`.
+    pub fn is_activity_days_locked(&self, work_order_number: &WorkOrderNumber, activity_number: ActivityNumber) -> Result<bool, ScheduleGraphErrors>
     {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        Ok(self.incidence_list()[activity_node_index]
+            .iter()
+            .any(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(_, LockState::Locked))))
+    }
+
+    /// Whether the `Period` is one of the `ScheduleGraph::locked_periods`.
+    pub fn is_period_locked(&self, period: &Period) -> Result<bool, ScheduleGraphErrors>
+    {
+        self.period_node_index(period)?;
+        if self.horizon_state().frozen_periods.contains(period) {
+            return Ok(true);
+        }
+
+        for day in period.start_date().iter_days().take_while(|day| *day <= period.end_date()) {
+            let Ok(&day_node_index) = self.day_node_index(&day) else {
+                continue;
+            };
+            if self.incidence_list()[day_node_index]
+                .iter()
+                .any(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(_, LockState::Locked)))
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Every `Period` where `Activity`s are already assigned to days
+    pub fn periods_with_activity_assignments(&self) -> HashSet<Period>
+    {
+        let periods = self.periods();
+
+        self.hyperedges()
+            .iter()
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Assign(Some(_), _)))
+            .flat_map(|hyperedge| hyperedge.nodes())
+            .filter_map(|&node_index| match self.nodes()[node_index] {
+                Node::Day(day) => periods.iter().find(|period| period.contains(day)).copied(),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The `Skill`s and available days of every `Technician` collected from
+    /// the `EdgeType::Available` hyperedges.
+    ///
+    /// Format
+    /// `vec![$technician, @skills, @days]`
+    pub fn technician_availabilities(&self) -> Vec<TechnicianAvailability>
+    {
+        let mut technician_availabilities: BTreeMap<TechnicianId, (BTreeSet<Skill>, BTreeSet<NaiveDate>)> = BTreeMap::new();
+        for hyperedge in self.hyperedges() {
+            if !matches!(hyperedge.edge_type(), EdgeType::Available(_)) {
+                continue;
+            }
+
+            let Node::Technician(technician_id) = self.nodes()[hyperedge.nodes()[0]] else {
+                unreachable!()
+            };
+            let (skills, days) = technician_availabilities.entry(technician_id).or_default();
+            for &node_index in &hyperedge.nodes()[1..] {
+                match &self.nodes()[node_index] {
+                    Node::Skill(skill) => {
+                        skills.insert(skill.clone());
+                    }
+                    Node::Day(day) => {
+                        days.insert(*day);
+                    }
+                    _ => (),
+                }
+            }
+        }
+
+        technician_availabilities
+            .into_iter()
+            .map(|(technician_id, (skills, days))| TechnicianAvailability {
+                technician_id,
+                skills: skills.into_iter().collect(),
+                days: days.into_iter().collect(),
+            })
+            .collect()
+    }
+
+    /// Working hours of every `Technician` in every `Period` derived from the
+    /// `EdgeType::Available` hyperedges. The hours are counted day by day, so
+    /// an availability that straddles two `Period`s is split between them.
+    /// Every `Skill` of the `Technician` can use all of the hours of the days
+    /// where its `Qualification` is valid.
+    ///
+    /// Format
+    /// `vec![$technician, @skills, @days]`
+    pub fn derive_technician_capacity(&self, working_hours_rule: &WorkingHoursRule) -> HashMap<Period, HashMap<TechnicianId, TechnicianCapacity>>
+    {
+        let mut technician_capacity: HashMap<Period, HashMap<TechnicianId, TechnicianCapacity>> = HashMap::new();
         for node in self.nodes() {
-            // ESSAY:
-            // What is the information that you want to get out here?
-            //
-            // You want to extract all the
-            // QUESTION: How to get the hours?
-            // ANSWER: For each Technician use the availabilities to
-            // calculate the correct
-            match node {
-                Node::Technician(e) => todo!(),
-                Node::WorkOrder(work_order_node_index) => {
-                    // Derive every hyperedge
-                    let hyperedge_indices = &self.incidence_list()[*work_order_node_index as usize];
-
-                    // Now we have to find each of the assignments
-                    //
-
-                    for hyperedge_index in hyperedge_indices {
-                        let hyperedge = &self.hyperedges()[*hyperedge_index];
-
-                        match hyperedge.edge_type() {
-                            _ => todo!(),
+            let Node::Technician(technician_id) = node else {
+                continue;
+            };
+            for (period, capacity) in self
+                .technician_capacity(technician_id, working_hours_rule)
+                .expect("The TechnicianId comes from the graph")
+            {
+                technician_capacity.entry(period).or_default().insert(*technician_id, capacity);
+            }
+        }
+        technician_capacity
+    }
+
+    /// Working hours of a single `Technician` in every `Period` where the
+    /// `Technician` has any. See `ScheduleGraph::derive_technician_capacity`.
+    ///
+    /// Format
+    /// `vec![$technician, @skills, @days]`
+    pub fn technician_capacity(
+        &self,
+        technician_id: &TechnicianId,
+        working_hours_rule: &WorkingHoursRule,
+    ) -> Result<HashMap<Period, TechnicianCapacity>, ScheduleGraphErrors>
+    {
+        let technician_node_index = *self.technician_node_index(technician_id)?;
+        let periods = self.periods();
+
+        let mut technician_capacity: HashMap<Period, TechnicianCapacity> = HashMap::new();
+        for &edge_index in &self.incidence_list()[technician_node_index] {
+            let hyperedge = &self.hyperedges()[edge_index];
+            let EdgeType::Available(availability) = hyperedge.edge_type() else {
+                continue;
+            };
+
+            let skills = hyperedge.nodes()[1..]
+                .iter()
+                .filter_map(|&node_index| match &self.nodes()[node_index] {
+                    Node::Skill(skill) => Some((node_index, skill)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+
+            for &node_index in &hyperedge.nodes()[1..] {
+                let Node::Day(day) = self.nodes()[node_index] else {
+                    continue;
+                };
+                let working_hours = availability.working_hours(day, working_hours_rule);
+                let Some(period) = periods.iter().find(|period| period.contains(day)) else {
+                    continue;
+                };
+                if working_hours == 0.0 {
+                    continue;
+                }
+
+                let capacity = technician_capacity.entry(*period).or_default();
+                capacity.total_hours += working_hours;
+                for &(skill_node_index, skill) in &skills {
+                    if self.is_technician_qualified_on(technician_node_index, skill_node_index, day) {
+                        *capacity.skill_hours.entry(skill.clone()).or_insert(0.0) += working_hours;
+                    }
+                }
+            }
+        }
+        Ok(technician_capacity)
+    }
+
+    /// The planned `Work` of a `WorkOrder` grouped by the `Skill` that each
+    /// `Activity` requires. The work of an `Activity` with a mixed crew is
+    /// split by the number of people of each `Skill`.
+    pub fn work_order_work_load(&self, work_order_number: &WorkOrderNumber) -> Result<HashMap<Skill, Work>, ScheduleGraphErrors>
+    {
+        let mut work_load = HashMap::new();
+        for activity_node_index in self.work_order_activities(work_order_number)? {
+            for (skill, work) in self.activity_work_load(activity_node_index) {
+                *work_load.entry(skill).or_insert(0.0) += work;
+            }
+        }
+        Ok(work_load)
+    }
+
+    /// Earliest start of every `Activity` in a `WorkOrder`. The first
+    /// `Activity` starts on the `BasicStart` day and every following one is
+    /// pushed by the `duration` of its predecessor when the two are related
+    /// by `EdgeType::FinishStart`.
+    pub fn activity_earliest_starts(
+        &self,
+        work_order_number: &WorkOrderNumber,
+    ) -> Result<BTreeMap<ActivityNumber, NaiveDateTime>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        let basic_start = self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::BasicStart))
+            .find_map(|node_index| match &self.nodes()[node_index] {
+                Node::Day(naive_date) => Some(naive_date.and_time(NaiveTime::MIN)),
+                _ => None,
+            })
+            .ok_or(ScheduleGraphErrors::DayMissing)?;
+
+        let mut earliest_starts: HashMap<NodeIndex, NaiveDateTime> = HashMap::new();
+        for activity_node_index in self.work_order_activities(work_order_number)? {
+            let mut earliest_start = basic_start;
+            for &edge_index in &self.incidence_list()[activity_node_index] {
+                // Format: `vec![$predecessor, $successor]`
+                let hyperedge = &self.hyperedges()[edge_index];
+                if !matches!(hyperedge.edge_type(), EdgeType::FinishStart | EdgeType::StartStart) || hyperedge.nodes()[1] != activity_node_index {
+                    continue;
+                }
+                let predecessor = hyperedge.nodes()[0];
+
+                let constraint = match (hyperedge.edge_type(), &self.nodes()[predecessor]) {
+                    (EdgeType::FinishStart, Node::Activity(predecessor_activity)) => earliest_starts[&predecessor] + predecessor_activity.duration(),
+                    _ => earliest_starts[&predecessor],
+                };
+                earliest_start = earliest_start.max(constraint);
+            }
+
+            earliest_starts.insert(activity_node_index, earliest_start);
+        }
+
+        Ok(earliest_starts
+            .into_iter()
+            .map(|(node_index, earliest_start)| match &self.nodes()[node_index] {
+                Node::Activity(activity) => (activity.activity_number(), earliest_start),
+                _ => unreachable!(),
+            })
+            .collect())
+    }
+}
+
+/// Private helper methods for the derivations
+impl ScheduleGraph
+{
+    /// Format
+    /// `vec![$work_order, $day]`, `vec![$work_order, $period, @days]` and
+    /// `vec![$activity, @technicians, @days]`
+    fn graph_work_order(&self, work_order_number: &WorkOrderNumber) -> Result<GraphWorkOrder, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        let basic_start = self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::BasicStart))
+            .find_map(|node_index| match self.nodes()[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            })
+            .ok_or(ScheduleGraphErrors::DayMissing)?;
+
+        let mut activities = BTreeMap::new();
+        for activity_node_index in self.work_order_activities(work_order_number)? {
+            let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                unreachable!()
+            };
+
+            let mut skill_requirements = vec![];
+            let mut assignments = vec![];
+            for &edge_index in &self.incidence_list()[activity_node_index] {
+                let hyperedge = &self.hyperedges()[edge_index];
+                match hyperedge.edge_type() {
+                    // Format: `vec![$activity, $skill]`
+                    EdgeType::Requires(number_of_people, minimum_proficiency) => {
+                        let Node::Skill(skill) = &self.nodes()[hyperedge.nodes()[1]] else {
+                            continue;
+                        };
+                        skill_requirements
+                            .push(SkillRequirement::new(skill.clone(), *number_of_people).with_minimum_proficiency(*minimum_proficiency));
+                    }
+                    EdgeType::Assign(start_and_finish_time, lock_state) => {
+                        let mut graph_assignment = GraphAssignment {
+                            technicians: BTreeSet::new(),
+                            days: vec![],
+                            start_and_finish_time: *start_and_finish_time,
+                            lock_state: *lock_state,
+                        };
+                        for &node_index in hyperedge.nodes() {
+                            match self.nodes()[node_index] {
+                                Node::Technician(technician_id) => {
+                                    graph_assignment.technicians.insert(technician_id);
+                                }
+                                Node::Day(day) => graph_assignment.days.push(day),
+                                _ => (),
+                            }
                         }
+                        graph_assignment.days.sort();
+                        assignments.push(graph_assignment);
                     }
+                    _ => (),
                 }
-                Node::Activity(_) => todo!(),
-                Node::Period(period) => todo!(),
-                Node::Skill(skill) => todo!(),
-                Node::Day(naive_date) => todo!(),
             }
+            skill_requirements.sort();
+            assignments.sort_by(|left, right| (&left.days, left.start_and_finish_time).cmp(&(&right.days, right.start_and_finish_time)));
+
+            let graph_activity = GraphActivity {
+                number_of_people: activity.number_of_people(),
+                work: activity.work(),
+                duration: activity.duration(),
+                skill_requirements,
+                predecessors: self.activity_predecessors(activity_node_index),
+                assignments,
+            };
+            activities.insert(activity.activity_number(), graph_activity);
+        }
+
+        Ok(GraphWorkOrder {
+            work_order_info: *self.work_order_info(work_order_number)?,
+            basic_start,
+            latest_finish: self.work_order_latest_finish(work_order_number)?,
+            exclusions: self.work_order_exclusions(work_order_number)?.into_iter().collect(),
+            assigned_period: self.work_order_assigned_period(work_order_number)?,
+            locked_period: self.work_order_locked_period(work_order_number)?,
+            is_completed: self.is_work_order_completed(work_order_number),
+            activities,
+        })
+    }
+
+    /// `Activity` nodes of a `WorkOrder` in the order they were added.
+    pub(crate) fn work_order_activities(&self, work_order_number: &WorkOrderNumber) -> Result<Vec<NodeIndex>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        Ok(self
+            .neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::Contains))
+            .collect())
+    }
+
+    /// The `Work` of the `Activity` split by the number of people of each
+    /// `Skill` in its crew.
+    pub(crate) fn activity_work_load(&self, activity_node_index: NodeIndex) -> HashMap<Skill, Work>
+    {
+        let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+            unreachable!()
+        };
+
+        let mut work_load = HashMap::new();
+        for &edge_index in &self.incidence_list()[activity_node_index] {
+            // Format: `vec![$activity, $skill]`
+            let hyperedge = &self.hyperedges()[edge_index];
+            let (EdgeType::Requires(number_of_people, _), Node::Skill(skill)) = (hyperedge.edge_type(), &self.nodes()[hyperedge.nodes()[1]]) else {
+                continue;
+            };
+
+            let share = *number_of_people as Work / activity.number_of_people() as Work;
+            *work_load.entry(skill.clone()).or_insert(0.0) += work_hours(activity.work()) * share;
         }
+        work_load
+    }
 
-        GraphWorkOrders {}
+    /// Every other node that shares a hyperedge accepted by `is_edge_type`
+    /// with the node.
+    pub(crate) fn neighbors_by_edge_type(
+        &self,
+        node_index: NodeIndex,
+        is_edge_type: impl Fn(&EdgeType) -> bool + 'static,
+    ) -> impl Iterator<Item = NodeIndex> + '_
+    {
+        self.incidence_list()[node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(move |hyperedge| is_edge_type(hyperedge.edge_type()))
+            .flat_map(|hyperedge| hyperedge.nodes().iter().copied())
+            .filter(move |&neighbor| neighbor != node_index)
     }
 }
 
-struct GraphWorkOrders {}
+/// Read model of the `WorkOrder`s in the `ScheduleGraph`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct GraphWorkOrders
+{
+    pub work_orders: BTreeMap<WorkOrderNumber, GraphWorkOrder>,
+    /// The requested `WorkOrderNumber`s that are not in the `ScheduleGraph`
+    pub missing_work_orders: Vec<WorkOrderNumber>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphWorkOrder
+{
+    pub work_order_info: WorkOrderInfo,
+    pub basic_start: NaiveDate,
+    pub latest_finish: Option<NaiveDate>,
+    pub exclusions: BTreeSet<Period>,
+    /// The `Period` of the strategic assignment
+    pub assigned_period: Option<Period>,
+    pub locked_period: Option<Period>,
+    pub is_completed: bool,
+    pub activities: BTreeMap<ActivityNumber, GraphActivity>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct GraphActivity
+{
+    pub number_of_people: NumberOfPeople,
+    pub work: TimeDelta,
+    pub duration: TimeDelta,
+    pub skill_requirements: Vec<SkillRequirement>,
+    pub predecessors: Vec<(ActivityNumber, ActivityRelation)>,
+    /// The tactical and the crew assignments of the `Activity` sorted by
+    /// their days.
+    pub assignments: Vec<GraphAssignment>,
+}
+
+/// An `EdgeType::Assign` of an `Activity`. A tactical assignment has neither
+/// `technicians` nor a `start_and_finish_time`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphAssignment
+{
+    pub technicians: BTreeSet<TechnicianId>,
+    pub days: Vec<NaiveDate>,
+    pub start_and_finish_time: Option<(StartTime, FinishTime)>,
+    pub lock_state: LockState,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TechnicianCapacity
+{
+    pub total_hours: Work,
+    pub skill_hours: HashMap<Skill, Work>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TechnicianAvailability
+{
+    pub technician_id: TechnicianId,
+    pub skills: Vec<Skill>,
+    pub days: Vec<NaiveDate>,
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use chrono::Weekday;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Proficiency;
+    use scheduling_environment::technician::Qualification;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::technician::WorkingHoursRule;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::SkillRequirement;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use super::GraphAssignment;
+    use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::LockState;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    /// The clock stands before every `Period` used by the tests so that all
+    /// of them are drafts.
+    fn mock_clock() -> MockClock
+    {
+        MockClock::new(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    fn schedule_graph_with_work_order() -> ScheduleGraph
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech"), Skill::new("MtnElec")]));
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            basic_start_date,
+            vec![
+                Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(16), TimeDelta::hours(8)),
+                Activity::new(20, 1, Skill::new("MtnElec"), TimeDelta::hours(4), TimeDelta::hours(4)),
+                Activity::new(30, 1, Skill::new("MtnMech"), TimeDelta::minutes(90), TimeDelta::hours(2)),
+                Activity::with_requirements(
+                    40,
+                    vec![
+                        SkillRequirement::new(Skill::new("MtnMech"), 3),
+                        SkillRequirement::new(Skill::new("MtnElec"), 1),
+                    ],
+                    TimeDelta::hours(8),
+                    TimeDelta::hours(2),
+                ),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        schedule_graph
+    }
+
+    #[test]
+    fn test_work_order_work_load()
+    {
+        let schedule_graph = schedule_graph_with_work_order();
+
+        let work_load = schedule_graph.work_order_work_load(&1122334455).unwrap();
+
+        assert_eq!(work_load.len(), 2);
+        assert_eq!(work_load[&Skill::new("MtnMech")], 23.5);
+        assert_eq!(work_load[&Skill::new("MtnElec")], 6.0);
+    }
+
+    #[test]
+    fn test_activity_earliest_starts()
+    {
+        let schedule_graph = schedule_graph_with_work_order();
+
+        let earliest_starts = schedule_graph.activity_earliest_starts(&1122334455).unwrap();
+
+        let basic_start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap().and_hms_opt(0, 0, 0).unwrap();
+        assert_eq!(earliest_starts[&10], basic_start);
+        assert_eq!(earliest_starts[&20], basic_start + TimeDelta::hours(8));
+        assert_eq!(earliest_starts[&30], basic_start + TimeDelta::hours(12));
+        assert_eq!(earliest_starts[&40], basic_start + TimeDelta::hours(14));
+    }
+
+    #[test]
+    fn test_derive_technician_capacity()
+    {
+        let mut schedule_graph = schedule_graph_with_work_order();
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        schedule_graph.add_period(period_1).unwrap();
+
+        // Friday morning in the first period until Tuesday noon in the second
+        let start = NaiveDate::from_ymd_opt(2025, 1, 24).unwrap().and_hms_opt(8, 0, 0).unwrap();
+        let end = NaiveDate::from_ymd_opt(2025, 1, 28).unwrap().and_hms_opt(12, 0, 0).unwrap();
+        // The certificate for `MtnElec` expires at the end of the first period
+        let qualification = Qualification::new(Proficiency::Certified)
+            .valid_between(start.date(), period_0.end_date())
+            .unwrap();
+        let technician = Technician::builder(1001)
+            .add_availability(start, end)
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .add_qualified_skill(Skill::new("MtnElec"), qualification)
+            .build();
+        schedule_graph.add_technician(technician, Availability::new(start, end)).unwrap();
+
+        let working_hours_rule = WorkingHoursRule::new(7.5, vec![Weekday::Sat, Weekday::Sun]);
+        let technician_capacity = schedule_graph.derive_technician_capacity(&working_hours_rule);
+
+        let capacity_0 = &technician_capacity[&period_0][&1001];
+        assert_eq!(capacity_0.total_hours, 7.5);
+        assert_eq!(capacity_0.skill_hours[&Skill::new("MtnMech")], 7.5);
+        assert_eq!(capacity_0.skill_hours[&Skill::new("MtnElec")], 7.5);
+
+        let capacity_1 = &technician_capacity[&period_1][&1001];
+        assert_eq!(capacity_1.total_hours, 15.0);
+        assert_eq!(capacity_1.skill_hours[&Skill::new("MtnMech")], 15.0);
+        assert!(!capacity_1.skill_hours.contains_key(&Skill::new("MtnElec")));
+
+        // The first and the last day are only partially covered by the availability
+        let technician_capacity = schedule_graph.derive_technician_capacity(&WorkingHoursRule::new(24.0, vec![]));
+        assert_eq!(technician_capacity[&period_0][&1001].total_hours, 16.0 + 24.0 + 24.0);
+        assert_eq!(technician_capacity[&period_1][&1001].total_hours, 24.0 + 12.0);
+    }
+
+    #[test]
+    fn test_work_order_latest_period()
+    {
+        let mut schedule_graph = schedule_graph_with_work_order();
+
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        let period_2 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 10).unwrap());
+        schedule_graph.add_period(period_1).unwrap();
+        schedule_graph.add_period(period_2).unwrap();
+
+        // Without a latest finish the `WorkOrder` may use the whole horizon
+        assert_eq!(schedule_graph.work_order_latest_finish(&1122334455), Ok(None));
+        assert_eq!(schedule_graph.work_order_latest_period(&1122334455), Ok(period_2));
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 14).unwrap();
+        let latest_finish_date = NaiveDate::from_ymd_opt(2025, 2, 7).unwrap();
+        let work_order = WorkOrder::new(1122334456, basic_start_date, vec![])
+            .unwrap()
+            .with_latest_finish(latest_finish_date)
+            .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        assert_eq!(schedule_graph.work_order_latest_finish(&1122334456), Ok(Some(latest_finish_date)));
+        assert_eq!(schedule_graph.work_order_latest_period(&1122334456), Ok(period_1));
+
+        // The day of the latest finish has to be in the graph
+        let work_order = WorkOrder::new(1122334457, basic_start_date, vec![])
+            .unwrap()
+            .with_latest_finish(NaiveDate::from_ymd_opt(2025, 3, 31).unwrap())
+            .unwrap();
+        assert_eq!(schedule_graph.add_work_order(&work_order), Err(ScheduleGraphErrors::DayMissing));
+        assert_eq!(
+            schedule_graph.work_order_latest_finish(&1122334457),
+            Err(ScheduleGraphErrors::WorkOrderMissing)
+        );
+
+        assert!(
+            WorkOrder::new(1122334458, basic_start_date, vec![])
+                .unwrap()
+                .with_latest_finish(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap())
+                .is_err()
+        );
+
+        // A deserialized `WorkOrder` skips `WorkOrder::with_latest_finish`
+        let work_order: WorkOrder = serde_json::from_value(serde_json::json!({
+            "work_order_number": 1122334458,
+            "basic_start_date": "2025-01-14",
+            "activities": [],
+            "latest_finish_date": "2025-01-13",
+        }))
+        .unwrap();
+        assert_eq!(
+            schedule_graph.add_work_order(&work_order),
+            Err(ScheduleGraphErrors::WorkOrderLatestFinishBeforeBasicStart)
+        );
+        assert_eq!(
+            schedule_graph.work_order_latest_finish(&1122334458),
+            Err(ScheduleGraphErrors::WorkOrderMissing)
+        );
+        // A deserialized `Activity` skips `WorkOrder::new`
+        let work_order = WorkOrder::new(
+            1122334458,
+            basic_start_date,
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        let mut value = serde_json::to_value(&work_order).unwrap();
+        value["activities"][0]["work"] = serde_json::json!(16.0);
+        let work_order: WorkOrder = serde_json::from_value(value).unwrap();
+        assert!(!work_order.activities()[0].is_valid());
+        assert_eq!(
+            schedule_graph.add_work_order(&work_order),
+            Err(ScheduleGraphErrors::WorkOrderActivityInvalidWork)
+        );
+        assert_eq!(
+            schedule_graph.work_order_latest_finish(&1122334458),
+            Err(ScheduleGraphErrors::WorkOrderMissing)
+        );
+    }
+
+    #[test]
+    fn test_freeze_assignments()
+    {
+        let mut schedule_graph = schedule_graph_with_work_order();
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        schedule_graph.add_period(period_1).unwrap();
+        let work_order = WorkOrder::new(1122334456, period_0.start_date(), vec![]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        // The last day of the first `Period`
+        let day = NaiveDate::from_ymd_opt(2025, 1, 26).unwrap();
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![1001],
+                1122334455,
+                10,
+                vec![day],
+                (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap()),
+            )
+            .unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334455, &period_0).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334456, &period_0).unwrap();
+
+        assert!(schedule_graph.locked_periods().is_empty());
+        assert_eq!(schedule_graph.work_order_locked_period(&1122334455), Ok(None));
+
+        schedule_graph.freeze_period(&period_0).unwrap();
+        assert_eq!(schedule_graph.locked_periods(), [period_0].into_iter().collect());
+        assert_eq!(schedule_graph.work_order_locked_period(&1122334455), Ok(Some(period_0)));
+        assert_eq!(
+            schedule_graph.remove_assignments_work_order(&1122334456),
+            Err(ScheduleGraphErrors::AssignmentLocked)
+        );
+        assert_eq!(
+            schedule_graph.freeze_work_order(&1122334456, &period_1),
+            Err(ScheduleGraphErrors::AssignmentLocked)
+        );
+
+        // Freezing a `WorkOrder` moves it into the `Period`
+        let work_order = WorkOrder::new(1122334457, period_0.start_date(), vec![]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334457, &period_0).unwrap();
+        let edge_index = schedule_graph.freeze_work_order(&1122334457, &period_1).unwrap();
+        assert_eq!(schedule_graph.freeze_work_order(&1122334457, &period_1), Ok(edge_index));
+        assert_eq!(schedule_graph.work_order_assigned_period(&1122334457), Ok(Some(period_1)));
+        assert_eq!(schedule_graph.work_order_locked_period(&1122334457), Ok(Some(period_1)));
+        assert_eq!(schedule_graph.locked_periods(), [period_0].into_iter().collect());
+    }
+
+    #[test]
+    fn test_derive_work_orders()
+    {
+        let mut schedule_graph = schedule_graph_with_work_order();
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        schedule_graph.add_period(period_1).unwrap();
+        schedule_graph.add_exclusion(&1122334455, &period_1).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334455, &period_0).unwrap();
+
+        let days = period_0.start_date().iter_days().take(2).collect::<Vec<_>>();
+        schedule_graph.add_assignment_activity_days(&1122334455, 10, days.clone()).unwrap();
+
+        let availability = Availability::new(days[0].and_hms_opt(7, 0, 0).unwrap(), days[1].and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnElec"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 20, vec![days[1]], start_and_finish_time)
+            .unwrap();
+
+        let graph_work_orders = schedule_graph.derive_work_orders(&[1122334455, 1122334499]).unwrap();
+
+        assert_eq!(graph_work_orders.missing_work_orders, vec![1122334499]);
+        let graph_work_order = &graph_work_orders.work_orders[&1122334455];
+        assert_eq!(graph_work_order.basic_start, period_0.start_date());
+        assert_eq!(graph_work_order.exclusions, [period_1].into_iter().collect());
+        assert_eq!(graph_work_order.assigned_period, Some(period_0));
+        assert_eq!(graph_work_order.locked_period, None);
+        assert!(!graph_work_order.is_completed);
+        assert_eq!(graph_work_order.activities.keys().copied().collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+
+        let first_activity = &graph_work_order.activities[&10];
+        assert_eq!(first_activity.skill_requirements, vec![SkillRequirement::new(Skill::new("MtnMech"), 2)]);
+        assert!(first_activity.predecessors.is_empty());
+        assert_eq!(
+            first_activity.assignments,
+            vec![GraphAssignment {
+                technicians: BTreeSet::new(),
+                days,
+                start_and_finish_time: None,
+                lock_state: LockState::Unlocked,
+            }]
+        );
+
+        let second_activity = &graph_work_order.activities[&20];
+        assert_eq!(second_activity.work, TimeDelta::hours(4));
+        assert_eq!(second_activity.predecessors, vec![(10, ActivityRelation::FinishStart)]);
+        assert_eq!(second_activity.assignments[0].technicians, [1001].into_iter().collect());
+        assert_eq!(second_activity.assignments[0].start_and_finish_time, Some(start_and_finish_time));
+
+        assert_eq!(graph_work_order.activities[&40].skill_requirements.len(), 2);
+
+        // An inconsistent `ScheduleGraph` is not reported as a missing
+        // `WorkOrder`
+        let basic_start_edge_index = schedule_graph
+            .hyperedges()
+            .iter()
+            .position(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::BasicStart))
+            .unwrap();
+        schedule_graph.remove_edge(basic_start_edge_index);
+        assert_eq!(
+            schedule_graph.derive_work_orders(&[1122334455, 1122334499]),
+            Err(ScheduleGraphErrors::DayMissing)
+        );
+    }
+}
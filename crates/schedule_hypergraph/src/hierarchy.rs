@@ -0,0 +1,488 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::TechnicianId;
+
+/// The planning level whose assignment breaks the hierarchy.
+#[derive(Hash, Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq)]
+pub enum PlanningLevel
+{
+    /// `vec![$work_order, $period]` and `vec![$technician, $work_order,
+    /// $period]`
+    Strategic,
+    /// `vec![$activity, @days]`
+    Tactical,
+    /// `vec![$activity, @technicians, @days]`
+    Supervisor,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HierarchyViolation
+{
+    /// The `WorkOrder` is assigned to more than one `Period`.
+    WorkOrderInSeveralPeriods
+    {
+        work_order_number: WorkOrderNumber,
+        periods: Vec<Period>,
+    },
+    /// An assignment of the `Activity` has days outside of the `Period`s that
+    /// its `WorkOrder` is assigned to. `assigned_periods` is empty when the
+    /// `WorkOrder` is not assigned at all.
+    ActivityOutsidePeriod
+    {
+        level: PlanningLevel,
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        assigned_periods: Vec<Period>,
+        days: Vec<NaiveDate>,
+    },
+    /// The `Technician` works on the `Activity` without being assigned to
+    /// its `WorkOrder`.
+    TechnicianNotOnWorkOrder
+    {
+        work_order_number: WorkOrderNumber,
+        activity_number: ActivityNumber,
+        technician_id: TechnicianId,
+    },
+    /// The `Technician` is assigned to the `WorkOrder` but works on none of
+    /// its `Activity`s while other `Technician`s do.
+    TechnicianWithoutActivity
+    {
+        work_order_number: WorkOrderNumber,
+        technician_id: TechnicianId,
+    },
+}
+
+impl HierarchyViolation
+{
+    pub fn level(&self) -> PlanningLevel
+    {
+        match self {
+            HierarchyViolation::WorkOrderInSeveralPeriods { .. } => PlanningLevel::Strategic,
+            HierarchyViolation::ActivityOutsidePeriod { level, .. } => *level,
+            HierarchyViolation::TechnicianNotOnWorkOrder { .. } | HierarchyViolation::TechnicianWithoutActivity { .. } => PlanningLevel::Supervisor,
+        }
+    }
+}
+
+/// Every `HierarchyViolation` of the `ScheduleGraph` grouped by the
+/// `PlanningLevel` that breaks the hierarchy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HierarchyReport
+{
+    pub violations: BTreeMap<PlanningLevel, Vec<HierarchyViolation>>,
+}
+
+impl HierarchyReport
+{
+    pub fn is_consistent(&self) -> bool
+    {
+        self.violations.is_empty()
+    }
+
+    pub fn level_violations(&self, planning_level: PlanningLevel) -> &[HierarchyViolation]
+    {
+        self.violations.get(&planning_level).map(Vec::as_slice).unwrap_or_default()
+    }
+}
+
+/// Public API to validate the assignments of the different planning levels
+/// against each other.
+impl ScheduleGraph
+{
+    /// Checks that the assignments of every `WorkOrder` agree across the
+    /// planning levels:
+    /// * A `WorkOrder` is assigned to at most one `Period`.
+    /// * The days of every assignment of an `Activity` lie inside of the
+    ///   `Period` that its `WorkOrder` is assigned to.
+    /// * When `Technician`s are assigned to the `WorkOrder`, the crews of its
+    ///   `Activity`s are made of exactly those `Technician`s.
+    pub fn validate_hierarchy(&self) -> HierarchyReport
+    {
+        let mut hierarchy_report = HierarchyReport::default();
+        for work_order_number in self.work_order_numbers() {
+            for hierarchy_violation in self.work_order_hierarchy_violations(&work_order_number) {
+                hierarchy_report
+                    .violations
+                    .entry(hierarchy_violation.level())
+                    .or_default()
+                    .push(hierarchy_violation);
+            }
+        }
+        hierarchy_report
+    }
+}
+
+impl ScheduleGraph
+{
+    fn work_order_hierarchy_violations(&self, work_order_number: &WorkOrderNumber) -> Vec<HierarchyViolation>
+    {
+        let work_order_node_index = *self
+            .work_order_node_index(work_order_number)
+            .expect("The WorkOrderNumber comes from the graph");
+
+        let mut assigned_periods = BTreeSet::new();
+        let mut work_order_technicians = BTreeSet::new();
+        for node_index in self.neighbors_by_edge_type(work_order_node_index, |edge_type| matches!(edge_type, EdgeType::Assign(None, _))) {
+            match self.nodes()[node_index] {
+                Node::Period(period) => {
+                    assigned_periods.insert(period);
+                }
+                Node::Technician(technician_id) => {
+                    work_order_technicians.insert(technician_id);
+                }
+                _ => (),
+            }
+        }
+        let assigned_periods = assigned_periods.into_iter().collect::<Vec<_>>();
+
+        let mut hierarchy_violations = vec![];
+        if assigned_periods.len() > 1 {
+            hierarchy_violations.push(HierarchyViolation::WorkOrderInSeveralPeriods {
+                work_order_number: *work_order_number,
+                periods: assigned_periods.clone(),
+            });
+        }
+
+        let mut activity_technicians = BTreeSet::new();
+        for activity_node_index in self
+            .work_order_activities(work_order_number)
+            .expect("The WorkOrderNumber comes from the graph")
+        {
+            let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                unreachable!()
+            };
+
+            for (level, technicians, days) in self.activity_assignments(activity_node_index) {
+                let outside_days = days
+                    .into_iter()
+                    .filter(|day| !assigned_periods.iter().any(|period| period.contains(*day)))
+                    .collect::<Vec<_>>();
+                if !outside_days.is_empty() {
+                    hierarchy_violations.push(HierarchyViolation::ActivityOutsidePeriod {
+                        level,
+                        work_order_number: *work_order_number,
+                        activity_number: activity.activity_number(),
+                        assigned_periods: assigned_periods.clone(),
+                        days: outside_days,
+                    });
+                }
+
+                for technician_id in technicians {
+                    if !work_order_technicians.is_empty() && !work_order_technicians.contains(&technician_id) {
+                        hierarchy_violations.push(HierarchyViolation::TechnicianNotOnWorkOrder {
+                            work_order_number: *work_order_number,
+                            activity_number: activity.activity_number(),
+                            technician_id,
+                        });
+                    }
+                    activity_technicians.insert(technician_id);
+                }
+            }
+        }
+
+        if !activity_technicians.is_empty() {
+            for technician_id in work_order_technicians.difference(&activity_technicians) {
+                hierarchy_violations.push(HierarchyViolation::TechnicianWithoutActivity {
+                    work_order_number: *work_order_number,
+                    technician_id: *technician_id,
+                });
+            }
+        }
+        hierarchy_violations
+    }
+
+    /// Format: `vec![$activity, @days]` or `vec![$activity, @technicians,
+    /// @days]`
+    fn activity_assignments(&self, activity_node_index: NodeIndex) -> Vec<(PlanningLevel, Vec<TechnicianId>, Vec<NaiveDate>)>
+    {
+        let mut activity_assignments = vec![];
+        for &edge_index in &self.incidence_list()[activity_node_index] {
+            let hyperedge = &self.hyperedges()[edge_index];
+            let level = match hyperedge.edge_type() {
+                EdgeType::Assign(None, _) => PlanningLevel::Tactical,
+                EdgeType::Assign(Some(_), _) => PlanningLevel::Supervisor,
+                _ => continue,
+            };
+
+            let mut technicians = vec![];
+            let mut days = vec![];
+            for &node_index in hyperedge.nodes() {
+                match self.nodes()[node_index] {
+                    Node::Technician(technician_id) => technicians.push(technician_id),
+                    Node::Day(day) => days.push(day),
+                    _ => (),
+                }
+            }
+            days.sort();
+            activity_assignments.push((level, technicians, days));
+        }
+        activity_assignments
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+    use scheduling_environment::work_order::WorkOrderNumber;
+
+    use super::HierarchyViolation;
+    use super::PlanningLevel;
+    use crate::schedule_graph::ScheduleGraph;
+
+    #[test]
+    fn test_validate_hierarchy()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let periods = [(1, 13), (1, 27)]
+            .into_iter()
+            .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+        for work_order_number in 1122334450..1122334454 {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                periods[0].start_date(),
+                vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let day = periods[0].start_date();
+        for technician_id in [1001, 1002] {
+            let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .add_skill(Skill::new("MtnMech"))
+                .build();
+            schedule_graph.add_technician(technician, availability).unwrap();
+        }
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap());
+
+        // Consistent on every level
+        schedule_graph.add_assignment_work_order(1001, 1122334450, periods[0]).unwrap();
+        schedule_graph.add_assignment_activity_days(&1122334450, 10, vec![day]).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334450, 10, vec![day], start_and_finish_time)
+            .unwrap();
+        assert!(schedule_graph.validate_hierarchy().is_consistent());
+
+        // The `Activity` is planned in the first `Period` but the `WorkOrder`
+        // is assigned to the second
+        schedule_graph.add_assignment_work_order_period(&1122334451, &periods[1]).unwrap();
+        schedule_graph.add_assignment_activity_days(&1122334451, 10, vec![day]).unwrap();
+
+        // The crew does not match the `Technician` of the `WorkOrder`
+        schedule_graph.add_assignment_work_order(1001, 1122334452, periods[0]).unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![1002],
+                1122334452,
+                10,
+                vec![day],
+                (NaiveTime::MIN, NaiveTime::from_hms_opt(2, 0, 0).unwrap()),
+            )
+            .unwrap();
+
+        // The `WorkOrder` is assigned to two `Period`s
+        schedule_graph.add_assignment_work_order_period(&1122334453, &periods[0]).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334453, &periods[1]).unwrap();
+
+        let hierarchy_report = schedule_graph.validate_hierarchy();
+
+        assert!(!hierarchy_report.is_consistent());
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Strategic),
+            [HierarchyViolation::WorkOrderInSeveralPeriods {
+                work_order_number: 1122334453,
+                periods: periods.clone(),
+            }]
+        );
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Tactical),
+            [HierarchyViolation::ActivityOutsidePeriod {
+                level: PlanningLevel::Tactical,
+                work_order_number: 1122334451,
+                activity_number: 10,
+                assigned_periods: vec![periods[1]],
+                days: vec![day],
+            }]
+        );
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Supervisor),
+            [
+                HierarchyViolation::TechnicianNotOnWorkOrder {
+                    work_order_number: 1122334452,
+                    activity_number: 10,
+                    technician_id: 1002,
+                },
+                HierarchyViolation::TechnicianWithoutActivity {
+                    work_order_number: 1122334452,
+                    technician_id: 1001,
+                },
+            ]
+        );
+    }
+
+    /// Every `WorkOrder` has one `Activity` for a crew of two and both
+    /// `Technician`s are available on the first day of the first `Period`.
+    fn schedule_graph_with_work_orders(work_order_numbers: &[WorkOrderNumber]) -> (ScheduleGraph, Vec<Period>)
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let periods = [(1, 13), (1, 27)]
+            .into_iter()
+            .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+        for &work_order_number in work_order_numbers {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                periods[0].start_date(),
+                vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(16), TimeDelta::hours(8))],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        let day = periods[0].start_date();
+        for technician_id in [1001, 1002] {
+            let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .add_skill(Skill::new("MtnMech"))
+                .build();
+            schedule_graph.add_technician(technician, availability).unwrap();
+        }
+        (schedule_graph, periods)
+    }
+
+    fn time_window(start_hour: u32) -> (NaiveTime, NaiveTime)
+    {
+        (
+            NaiveTime::from_hms_opt(start_hour, 0, 0).unwrap(),
+            NaiveTime::from_hms_opt(start_hour + 2, 0, 0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_validate_hierarchy_technicians()
+    {
+        let (mut schedule_graph, periods) = schedule_graph_with_work_orders(&[1122334450, 1122334451, 1122334452, 1122334453]);
+        let day = periods[0].start_date();
+
+        // Only one of the `Technician`s of the `WorkOrder` is in the crew
+        schedule_graph.add_assignment_work_order(1001, 1122334450, periods[0]).unwrap();
+        schedule_graph.add_assignment_work_order(1002, 1122334450, periods[0]).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334450, 10, vec![day], time_window(8))
+            .unwrap();
+
+        // The crew is not bound when no `Technician` is assigned to the
+        // `WorkOrder`
+        schedule_graph.add_assignment_work_order_period(&1122334451, &periods[0]).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1002], 1122334451, 10, vec![day], time_window(10))
+            .unwrap();
+
+        // The `Technician` is not missing from a crew that is not delegated yet
+        schedule_graph.add_assignment_work_order(1001, 1122334452, periods[0]).unwrap();
+
+        // Only one of the crew is assigned to the `WorkOrder`
+        schedule_graph.add_assignment_work_order(1001, 1122334453, periods[0]).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001, 1002], 1122334453, 10, vec![day], time_window(12))
+            .unwrap();
+
+        let hierarchy_report = schedule_graph.validate_hierarchy();
+
+        assert!(hierarchy_report.level_violations(PlanningLevel::Strategic).is_empty());
+        assert!(hierarchy_report.level_violations(PlanningLevel::Tactical).is_empty());
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Supervisor),
+            [
+                HierarchyViolation::TechnicianWithoutActivity {
+                    work_order_number: 1122334450,
+                    technician_id: 1002,
+                },
+                HierarchyViolation::TechnicianNotOnWorkOrder {
+                    work_order_number: 1122334453,
+                    activity_number: 10,
+                    technician_id: 1002,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_hierarchy_activity_outside_period()
+    {
+        let (mut schedule_graph, periods) = schedule_graph_with_work_orders(&[1122334450, 1122334451]);
+        let day = periods[0].start_date();
+
+        // The `WorkOrder` is not assigned to any `Period`
+        schedule_graph.add_assignment_activity_days(&1122334450, 10, vec![day]).unwrap();
+
+        // Only the crew lies outside of the `Period` of the `WorkOrder`
+        schedule_graph.add_assignment_work_order_period(&1122334451, &periods[1]).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334451, 10, vec![day], time_window(8))
+            .unwrap();
+
+        let hierarchy_report = schedule_graph.validate_hierarchy();
+
+        assert!(hierarchy_report.level_violations(PlanningLevel::Strategic).is_empty());
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Tactical),
+            [HierarchyViolation::ActivityOutsidePeriod {
+                level: PlanningLevel::Tactical,
+                work_order_number: 1122334450,
+                activity_number: 10,
+                assigned_periods: vec![],
+                days: vec![day],
+            }]
+        );
+        assert_eq!(
+            hierarchy_report.level_violations(PlanningLevel::Supervisor),
+            [HierarchyViolation::ActivityOutsidePeriod {
+                level: PlanningLevel::Supervisor,
+                work_order_number: 1122334451,
+                activity_number: 10,
+                assigned_periods: vec![periods[1]],
+                days: vec![day],
+            }]
+        );
+    }
+}
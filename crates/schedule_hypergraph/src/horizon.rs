@@ -0,0 +1,362 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+use chrono::Days;
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodState;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::change_log::GraphChange;
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::FinishTime;
+use crate::schedule_graph::LockState;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::StartTime;
+use crate::schedule_graph::TechnicianId;
+
+/// The part of the graph that has passed. The `Period` nodes of archived
+/// `Period`s are kept so that the indices stay valid, but they are no longer
+/// a part of the horizon. The `Day` nodes are kept as well as `WorkOrder`s
+/// may still have a `BasicStart` in the past.
+#[derive(Default, Debug)]
+pub(crate) struct HorizonState
+{
+    pub(crate) archived_periods: BTreeSet<Period>,
+    /// `Period`s frozen by `ScheduleGraph::freeze_period`. A frozen
+    /// `WorkOrder` leaves the same locked hyperedge as a `WorkOrder` in a
+    /// frozen `Period`, so the frozen `Period`s are kept here.
+    pub(crate) frozen_periods: BTreeSet<Period>,
+    pub(crate) history: Vec<ArchivedAssignment>,
+    pub(crate) completed_work_orders: HashSet<WorkOrderNumber>,
+}
+
+/// An `EdgeType::Assign` hyperedge that was removed from the graph when its
+/// `Period` passed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArchivedAssignment
+{
+    pub period: Period,
+    pub work_order_number: WorkOrderNumber,
+    /// `None` for assignments of the whole `WorkOrder` to the `Period`
+    pub activity_number: Option<ActivityNumber>,
+    pub technicians: Vec<TechnicianId>,
+    pub days: Vec<NaiveDate>,
+    pub start_and_finish_time: Option<(StartTime, FinishTime)>,
+    pub lock_state: LockState,
+}
+
+/// Report of `ScheduleGraph::roll_horizon`
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct RolledHorizon
+{
+    pub archived_periods: Vec<Period>,
+    pub new_periods: Vec<Period>,
+    /// `WorkOrder`s that had assignments in an archived `Period` but were
+    /// not completed, sorted by `WorkOrderNumber`.
+    pub unfinished_work_orders: Vec<WorkOrderNumber>,
+}
+
+/// Public API to move the planning horizon forward in time.
+impl ScheduleGraph
+{
+    /// Archives every `Period` that the `SystemClock` considers `Previous`
+    /// and appends the same number of new `Period`s to the end of the
+    /// horizon. The assignments of the archived `Period`s are moved out of
+    /// the graph and into the assignment history.
+    pub fn roll_horizon(&mut self) -> Result<RolledHorizon, ScheduleGraphErrors>
+    {
+        let mut previous_periods = vec![];
+        for period in self.periods() {
+            if self.period_state(&period)? == PeriodState::Previous {
+                previous_periods.push(period);
+            }
+        }
+        if previous_periods.is_empty() {
+            return Ok(RolledHorizon::default());
+        }
+
+        // An assignment spanning two archived `Period`s belongs to the first
+        let mut archived_edges: BTreeMap<EdgeIndex, Period> = BTreeMap::new();
+        for period in &previous_periods {
+            for edge_index in self.find_all_assignments_for_period(*period)? {
+                archived_edges.entry(edge_index).or_insert(*period);
+            }
+        }
+
+        let mut unfinished_work_orders = BTreeSet::new();
+        for (&edge_index, &period) in &archived_edges {
+            let archived_assignment = self.archived_assignment(edge_index, period);
+            if !self.is_work_order_completed(&archived_assignment.work_order_number) {
+                unfinished_work_orders.insert(archived_assignment.work_order_number);
+            }
+            self.horizon_state_mut().history.push(archived_assignment);
+        }
+
+        // Removing the largest `EdgeIndex` first keeps the remaining ones valid.
+        for &edge_index in archived_edges.keys().rev() {
+            self.remove_edge(edge_index);
+        }
+
+        let mut last_period = *self.periods().last().expect("There are Previous Periods");
+        let mut new_periods = vec![];
+        for period in &previous_periods {
+            self.horizon_state_mut().archived_periods.insert(*period);
+            self.record_change(GraphChange::Period(*period));
+
+            last_period = Period::from_start_date(last_period.end_date() + Days::new(1));
+            self.add_period(last_period)?;
+            new_periods.push(last_period);
+        }
+
+        Ok(RolledHorizon {
+            archived_periods: previous_periods,
+            new_periods,
+            unfinished_work_orders: unfinished_work_orders.into_iter().collect(),
+        })
+    }
+
+    /// Marks the `WorkOrder` as carried out. Completed `WorkOrder`s are not
+    /// reported as unfinished when their `Period` is archived.
+    pub fn complete_work_order(&mut self, work_order_number: &WorkOrderNumber) -> Result<(), ScheduleGraphErrors>
+    {
+        self.work_order_node_index(work_order_number)?;
+        self.horizon_state_mut().completed_work_orders.insert(*work_order_number);
+        self.record_change(GraphChange::WorkOrder(*work_order_number));
+        Ok(())
+    }
+
+    pub fn is_work_order_completed(&self, work_order_number: &WorkOrderNumber) -> bool
+    {
+        self.horizon_state().completed_work_orders.contains(work_order_number)
+    }
+
+    /// Every assignment archived by `ScheduleGraph::roll_horizon` in the
+    /// order they were archived.
+    pub fn assignment_history(&self) -> &[ArchivedAssignment]
+    {
+        &self.horizon_state().history
+    }
+
+    pub fn is_period_archived(&self, period: &Period) -> bool
+    {
+        self.horizon_state().archived_periods.contains(period)
+    }
+}
+
+impl ScheduleGraph
+{
+    /// Format
+    /// `vec![$work_order, $period]`, `vec![@technician, $work_order, $period]`
+    /// or `vec![$activity, @technicians, @days]`
+    fn archived_assignment(&self, edge_index: EdgeIndex, period: Period) -> ArchivedAssignment
+    {
+        let hyperedge = &self.hyperedges()[edge_index];
+        let EdgeType::Assign(start_and_finish_time, lock_state) = *hyperedge.edge_type() else {
+            unreachable!("Only assignments are archived")
+        };
+
+        let mut work_order_number = None;
+        let mut activity_number = None;
+        let mut technicians = vec![];
+        let mut days = vec![];
+        for &node_index in hyperedge.nodes() {
+            match &self.nodes()[node_index] {
+                Node::WorkOrder(number) => work_order_number = Some(*number),
+                Node::Activity(activity_node) => {
+                    activity_number = Some(activity_node.activity_number());
+                    work_order_number = Some(self.activity_work_order(node_index));
+                }
+                Node::Technician(technician_id) => technicians.push(*technician_id),
+                Node::Day(day) => days.push(*day),
+                Node::Period(_) | Node::Skill(_) => (),
+            }
+        }
+
+        ArchivedAssignment {
+            period,
+            work_order_number: work_order_number.expect("Every assignment belongs to a WorkOrder"),
+            activity_number,
+            technicians,
+            days,
+            start_and_finish_time,
+            lock_state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use super::ArchivedAssignment;
+    use super::RolledHorizon;
+    use crate::schedule_graph::LockState;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_roll_horizon()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        let period_2 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 10).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period_0).unwrap();
+        schedule_graph.add_period(period_1).unwrap();
+
+        for work_order_number in [1122334455, 1122334457, 1122334458] {
+            let work_order = WorkOrder::new(work_order_number, period_0.start_date(), vec![]).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+        let work_order = WorkOrder::new(
+            1122334456,
+            period_0.start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2025, 1, 14).unwrap();
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap());
+        schedule_graph.add_assignment_work_order_period(&1122334455, &period_0).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334456, 10, vec![day], start_and_finish_time)
+            .unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334457, &period_0).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334458, &period_1).unwrap();
+        schedule_graph.complete_work_order(&1122334457).unwrap();
+
+        // Nothing has passed yet
+        assert_eq!(schedule_graph.roll_horizon(), Ok(RolledHorizon::default()));
+
+        system_clock.set(period_1.start_date().and_hms_opt(6, 0, 0).unwrap());
+        let rolled_horizon = schedule_graph.roll_horizon().unwrap();
+
+        assert_eq!(
+            rolled_horizon,
+            RolledHorizon {
+                archived_periods: vec![period_0],
+                new_periods: vec![period_2],
+                unfinished_work_orders: vec![1122334455, 1122334456],
+            }
+        );
+        assert_eq!(schedule_graph.periods(), vec![period_1, period_2]);
+        assert!(schedule_graph.is_period_archived(&period_0));
+
+        assert_eq!(schedule_graph.assignment_history().len(), 3);
+        assert!(schedule_graph.assignment_history().contains(&ArchivedAssignment {
+            period: period_0,
+            work_order_number: 1122334456,
+            activity_number: Some(10),
+            technicians: vec![1001],
+            days: vec![day],
+            start_and_finish_time: Some(start_and_finish_time),
+            lock_state: LockState::Unlocked,
+        }));
+
+        // Only the assignments of the archived `Period` leave the graph
+        assert_eq!(schedule_graph.work_order_assigned_period(&1122334455), Ok(None));
+        assert_eq!(schedule_graph.work_order_assigned_period(&1122334458), Ok(Some(period_1)));
+        assert!(schedule_graph.find_all_assignments_for_period(period_0).unwrap().is_empty());
+
+        assert_eq!(schedule_graph.roll_horizon(), Ok(RolledHorizon::default()));
+    }
+
+    #[test]
+    fn test_roll_horizon_several_periods()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let periods = [(1, 13), (1, 27), (2, 10), (2, 24), (3, 10)]
+            .into_iter()
+            .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+            .collect::<Vec<_>>();
+        for period in &periods[..3] {
+            schedule_graph.add_period(*period).unwrap();
+        }
+
+        for work_order_number in [1122334455, 1122334456, 1122334457] {
+            let work_order = WorkOrder::new(work_order_number, periods[0].start_date(), vec![]).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+        schedule_graph.freeze_work_order(&1122334455, &periods[0]).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334456, &periods[1]).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334457, &periods[2]).unwrap();
+
+        // The first two `Period`s pass at once
+        system_clock.set(periods[2].start_date().and_hms_opt(6, 0, 0).unwrap());
+        let rolled_horizon = schedule_graph.roll_horizon().unwrap();
+
+        assert_eq!(
+            rolled_horizon,
+            RolledHorizon {
+                archived_periods: periods[..2].to_vec(),
+                new_periods: periods[3..].to_vec(),
+                unfinished_work_orders: vec![1122334455, 1122334456],
+            }
+        );
+        assert_eq!(schedule_graph.periods(), periods[2..].to_vec());
+
+        // The lock of an archived assignment is kept in the history
+        assert_eq!(
+            schedule_graph.assignment_history(),
+            [
+                ArchivedAssignment {
+                    period: periods[0],
+                    work_order_number: 1122334455,
+                    activity_number: None,
+                    technicians: vec![],
+                    days: vec![],
+                    start_and_finish_time: None,
+                    lock_state: LockState::Locked,
+                },
+                ArchivedAssignment {
+                    period: periods[1],
+                    work_order_number: 1122334456,
+                    activity_number: None,
+                    technicians: vec![],
+                    days: vec![],
+                    start_and_finish_time: None,
+                    lock_state: LockState::Unlocked,
+                },
+            ]
+        );
+        assert_eq!(schedule_graph.work_order_assigned_period(&1122334455), Ok(None));
+        assert_eq!(schedule_graph.work_order_assigned_period(&1122334457), Ok(Some(periods[2])));
+
+        // An archived `Period` can not be assigned to again
+        assert_eq!(
+            schedule_graph.add_assignment_work_order_period(&1122334455, &periods[0]),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+    }
+}
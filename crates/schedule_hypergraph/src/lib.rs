@@ -1,4 +1,11 @@
+pub mod change_log;
 pub mod derive_instances;
+pub mod hierarchy;
+pub mod horizon;
+pub mod operational_instance;
 /// The goal of the crate is to replace `petgraph` in the ordinator
 /// schedule system with a complete domain graph.
 pub mod schedule_graph;
+pub mod staffing;
+pub mod supervisor_instance;
+pub mod tactical_instance;
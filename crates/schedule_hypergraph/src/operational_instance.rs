@@ -0,0 +1,354 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use scheduling_environment::work_order::work_hours;
+
+use crate::change_log::GraphChange;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::FinishTime;
+use crate::schedule_graph::LockState;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::StartTime;
+use crate::schedule_graph::TechnicianId;
+
+/// The view of a single `Technician` on a range of `Day`s. It holds
+/// everything an operational scheduler needs to sequence the work of the
+/// `Technician` inside of each `Day`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OperationalInstance
+{
+    pub technician_id: TechnicianId,
+    /// Every date of the range, also the ones without any availability.
+    pub operational_days: BTreeMap<NaiveDate, OperationalDay>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct OperationalDay
+{
+    /// The shift of the `Technician` on the `Day`. An `Availability` is read
+    /// as the same shift on every `Day` it covers. `None` when the
+    /// `Technician` is not available or when the `Availability` runs over
+    /// midnight.
+    pub shift: Option<(StartTime, FinishTime)>,
+    /// Working hours of the `Day` limited by the `WorkingHoursRule`
+    pub available_hours: Work,
+    /// The unlocked assignments of the `Technician` ordered by their start.
+    /// The operational scheduler is free to move them inside of the `shift`.
+    pub activities: Vec<OperationalActivity>,
+    /// The locked assignments of the `Technician` ordered by their start.
+    /// The operational scheduler has to plan around them.
+    pub fixed_events: Vec<OperationalActivity>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OperationalActivity
+{
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub time_window: (StartTime, FinishTime),
+}
+
+impl OperationalActivity
+{
+    pub fn work(&self) -> Work
+    {
+        work_hours(self.time_window.1 - self.time_window.0)
+    }
+}
+
+impl OperationalDay
+{
+    /// Hours of the `Day` that are already booked by assignments
+    pub fn booked_hours(&self) -> Work
+    {
+        self.activities.iter().chain(&self.fixed_events).map(OperationalActivity::work).sum()
+    }
+}
+
+/// Public API to derive the instance for the operational planning.
+impl ScheduleGraph
+{
+    /// Derives the `OperationalInstance` of the `Technician` from
+    /// `start_date` to `finish_date`, both included.
+    ///
+    /// Format
+    /// `vec![$technician, @skills, @days]` and
+    /// `vec![$activity, @technicians, @days]`
+    pub fn derive_operational_instance(
+        &self,
+        technician_id: TechnicianId,
+        start_date: NaiveDate,
+        finish_date: NaiveDate,
+        working_hours_rule: &WorkingHoursRule,
+    ) -> Result<OperationalInstance, ScheduleGraphErrors>
+    {
+        let technician_node_index = *self.technician_node_index(&technician_id)?;
+
+        let mut operational_days = start_date
+            .iter_days()
+            .take_while(|day| *day <= finish_date)
+            .map(|day| (day, OperationalDay::default()))
+            .collect::<BTreeMap<_, _>>();
+
+        for &edge_index in &self.incidence_list()[technician_node_index] {
+            let hyperedge = &self.hyperedges()[edge_index];
+            let days = hyperedge.nodes().iter().filter_map(|&node_index| match self.nodes()[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            });
+
+            match hyperedge.edge_type() {
+                EdgeType::Available(availability) => {
+                    for day in days {
+                        let Some(operational_day) = operational_days.get_mut(&day) else {
+                            continue;
+                        };
+                        let working_hours = availability.working_hours(day, working_hours_rule);
+                        if working_hours == 0.0 {
+                            continue;
+                        }
+                        operational_day.available_hours += working_hours;
+                        if availability.start().time() < availability.end().time() {
+                            operational_day.shift = Some((availability.start().time(), availability.end().time()));
+                        }
+                    }
+                }
+                EdgeType::Assign(Some(time_window), lock_state) => {
+                    let activity_node_index = hyperedge.nodes()[0];
+                    let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                        unreachable!("Only `Activity`s are assigned to `Day`s")
+                    };
+                    let operational_activity = OperationalActivity {
+                        work_order_number: self.activity_work_order(activity_node_index),
+                        activity_number: activity.activity_number(),
+                        time_window: *time_window,
+                    };
+
+                    for day in days {
+                        let Some(operational_day) = operational_days.get_mut(&day) else {
+                            continue;
+                        };
+                        match lock_state {
+                            LockState::Unlocked => operational_day.activities.push(operational_activity.clone()),
+                            LockState::Locked => operational_day.fixed_events.push(operational_activity.clone()),
+                        }
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        for operational_day in operational_days.values_mut() {
+            operational_day
+                .activities
+                .sort_by_key(|operational_activity| operational_activity.time_window);
+            operational_day
+                .fixed_events
+                .sort_by_key(|operational_activity| operational_activity.time_window);
+        }
+
+        Ok(OperationalInstance {
+            technician_id,
+            operational_days,
+        })
+    }
+
+    /// Derives the `OperationalInstance` again for the same `Day`s when one
+    /// of the `GraphChange`s touches its `Technician`. Every availability and
+    /// assignment of a `Technician` holds the `Technician` node, so other
+    /// changes can not affect it. It is also derived again after a
+    /// `GraphChange::ResyncRequired`. Returns whether it was derived again.
+    pub fn update_operational_instance(
+        &self,
+        operational_instance: &mut OperationalInstance,
+        working_hours_rule: &WorkingHoursRule,
+        graph_changes: &[GraphChange],
+    ) -> Result<bool, ScheduleGraphErrors>
+    {
+        if !graph_changes.contains(&GraphChange::Technician(operational_instance.technician_id))
+            && !graph_changes.contains(&GraphChange::ResyncRequired)
+        {
+            return Ok(false);
+        }
+        let (Some(&start_date), Some(&finish_date)) = (
+            operational_instance.operational_days.keys().next(),
+            operational_instance.operational_days.keys().next_back(),
+        ) else {
+            return Ok(false);
+        };
+
+        *operational_instance = self.derive_operational_instance(operational_instance.technician_id, start_date, finish_date, working_hours_rule)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::technician::WorkingHoursRule;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use super::OperationalActivity;
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_derive_operational_instance()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            period.start_date(),
+            vec![
+                Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8)),
+                Activity::new(20, 1, Skill::new("MtnMech"), TimeDelta::hours(4), TimeDelta::hours(4)),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let days = period.start_date().iter_days().take(3).collect::<Vec<_>>();
+        let availability = Availability::new(days[0].and_hms_opt(7, 0, 0).unwrap(), days[1].and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 20, vec![days[1]], (time(8), time(10)))
+            .unwrap();
+        schedule_graph.freeze_period(&period).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 20, vec![days[0]], (time(13), time(15)))
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 10, vec![days[0]], (time(8), time(12)))
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 10, vec![days[1]], (time(10), time(14)))
+            .unwrap();
+
+        let operational_instance = schedule_graph
+            .derive_operational_instance(1001, days[0], days[2], &WorkingHoursRule::default())
+            .unwrap();
+        assert_eq!(operational_instance.operational_days.len(), 3);
+
+        let first_day = &operational_instance.operational_days[&days[0]];
+        assert_eq!(first_day.shift, Some((time(7), time(17))));
+        assert_eq!(first_day.available_hours, 8.0);
+        assert_eq!(
+            first_day
+                .activities
+                .iter()
+                .map(|operational_activity| operational_activity.activity_number)
+                .collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+        assert!(first_day.fixed_events.is_empty());
+
+        // The assignments made before the `Period` was frozen are locked
+        let second_day = &operational_instance.operational_days[&days[1]];
+        assert_eq!(
+            second_day.fixed_events,
+            vec![OperationalActivity {
+                work_order_number: 1122334455,
+                activity_number: 20,
+                time_window: (time(8), time(10)),
+            }]
+        );
+        assert_eq!(second_day.activities.len(), 1);
+        assert_eq!(second_day.booked_hours(), 6.0);
+
+        let third_day = &operational_instance.operational_days[&days[2]];
+        assert_eq!(third_day.shift, None);
+        assert_eq!(third_day.available_hours, 0.0);
+
+        assert_eq!(
+            schedule_graph.derive_operational_instance(1002, days[0], days[2], &WorkingHoursRule::default()),
+            Err(ScheduleGraphErrors::WorkerMissing)
+        );
+    }
+
+    #[test]
+    fn test_update_operational_instance()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+        let work_order = WorkOrder::new(
+            1122334455,
+            period.start_date(),
+            vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let day = period.start_date();
+        for technician_id in [1001, 1002] {
+            let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .add_skill(Skill::new("MtnMech"))
+                .build();
+            schedule_graph.add_technician(technician, availability).unwrap();
+        }
+
+        let working_hours_rule = WorkingHoursRule::default();
+        let mut operational_instance = schedule_graph.derive_operational_instance(1001, day, day, &working_hours_rule).unwrap();
+        let mut change_subscription = schedule_graph.subscribe();
+
+        // An assignment of another `Technician` does not touch the instance
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1002], 1122334455, 10, vec![day], (time(8), time(12)))
+            .unwrap();
+        let graph_changes = change_subscription.poll(&schedule_graph);
+        assert_eq!(
+            schedule_graph.update_operational_instance(&mut operational_instance, &working_hours_rule, graph_changes),
+            Ok(false)
+        );
+
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 10, vec![day], (time(12), time(16)))
+            .unwrap();
+        let graph_changes = change_subscription.poll(&schedule_graph);
+        assert_eq!(
+            schedule_graph.update_operational_instance(&mut operational_instance, &working_hours_rule, graph_changes),
+            Ok(true)
+        );
+        assert_eq!(operational_instance.operational_days[&day].activities.len(), 1);
+        assert_eq!(
+            operational_instance,
+            schedule_graph.derive_operational_instance(1001, day, day, &working_hours_rule).unwrap()
+        );
+    }
+}
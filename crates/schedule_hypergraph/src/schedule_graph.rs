@@ -6,17 +6,30 @@ use chrono::Days;
 use chrono::Duration;
 use chrono::NaiveDate;
 use chrono::NaiveTime;
+use chrono::TimeDelta;
 use scheduling_environment::Period;
+use scheduling_environment::PeriodState;
+use scheduling_environment::system_clock::RealClock;
+use scheduling_environment::system_clock::SystemClock;
 use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Proficiency;
+use scheduling_environment::technician::Qualification;
 use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillCatalog;
 use scheduling_environment::technician::Technician;
+use scheduling_environment::work_order::Activity;
 use scheduling_environment::work_order::ActivityNumber;
 use scheduling_environment::work_order::ActivityRelation;
 use scheduling_environment::work_order::NumberOfPeople;
 use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::WorkOrderInfo;
 use scheduling_environment::work_order::WorkOrderNumber;
 use tracing::debug;
 
+use crate::change_log::ChangeLog;
+use crate::change_log::GraphChange;
+use crate::horizon::HorizonState;
+
 // Type Alias to make reasoning about the indices easier
 pub type NodeIndex = usize;
 pub type EdgeIndex = usize;
@@ -24,6 +37,7 @@ pub type TechnicianId = usize;
 pub type StartTime = NaiveTime;
 pub type FinishTime = NaiveTime;
 
+#[allow(dead_code)]
 const HYPEREDGE_NODE_SEPERATOR: usize = usize::MAX; // Reserved sentinel value
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
@@ -34,13 +48,26 @@ pub enum ScheduleGraphErrors
     PeriodDuplicate,
     PeriodMissing,
     SkillMissing,
+    SkillNotInCatalog,
     WorkOrderActivityMissingSkills,
     WorkOrderDuplicate,
     WorkOrderMissing,
+    WorkOrderLatestFinishBeforeBasicStart,
+    WorkOrderActivityInvalidWork,
     WorkerUnavailable,
     WorkerMissing,
     WorkerDuplicate,
+    WorkerMissingSkill,
+    WorkerInsufficientProficiency,
+    WorkerQualificationExpired,
     ActivityExceedNumberOfPeople,
+    ActivityExceedSkillRequirement,
+    ActivityExceedWork,
+    AssignmentLocked,
+    PeriodPrevious,
+    PeriodFrozen,
+    ActivityUnplanned,
+    AssignmentMissing,
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
@@ -79,26 +106,66 @@ pub(crate) struct ActivityNode
 {
     activity_number: ActivityNumber,
     number_of_people: NumberOfPeople,
+    work: TimeDelta,
+    duration: TimeDelta,
+}
+
+impl ActivityNode
+{
+    pub(crate) fn activity_number(&self) -> ActivityNumber
+    {
+        self.activity_number
+    }
+
+    pub(crate) fn number_of_people(&self) -> NumberOfPeople
+    {
+        self.number_of_people
+    }
+
+    pub(crate) fn work(&self) -> TimeDelta
+    {
+        self.work
+    }
+
+    pub(crate) fn duration(&self) -> TimeDelta
+    {
+        self.duration
+    }
+}
+
+/// A `Locked` assignment is frozen and may not be moved or removed by the
+/// planning.
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq)]
+pub enum LockState
+{
+    #[default]
+    Unlocked,
+    Locked,
 }
 
 #[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq)]
 pub enum EdgeType
 {
     /// Date specific
-    Assign(Option<(StartTime, FinishTime)>),
+    Assign(Option<(StartTime, FinishTime)>, LockState),
 
     /// FORMAT
-    /// `vec![$activity, @technicians, @days]`
-    Available,
+    /// `vec![$technician, @skills, @days]`
+    Available(Availability),
     Exclude,
     BasicStart,
+    /// FORMAT
+    /// `vec![$work_order, $day]`
+    LatestFinish,
 
     Contains,
-    Requires,
+    /// The `Activity` requires a crew of the `Skill` at least at the given
+    /// level.
+    Requires(NumberOfPeople, Proficiency),
     StartStart,
     FinishStart,
     /// Has skill
-    HasSkill,
+    HasSkill(Qualification),
 }
 
 #[derive(Debug)]
@@ -124,6 +191,23 @@ pub struct ScheduleGraph
     period_indices: HashMap<Period, NodeIndex>,
     skill_indices: HashMap<Skill, NodeIndex>,
     day_indices: BTreeMap<NaiveDate, NodeIndex>,
+
+    /// The only `Skill`s that may be added to the graph
+    skill_catalog: SkillCatalog,
+
+    /// Attributes of the `WorkOrder`s that are not part of the structure of
+    /// the graph.
+    work_order_infos: HashMap<WorkOrderNumber, WorkOrderInfo>,
+
+    /// Decides the `PeriodState` of every `Period`
+    system_clock: Box<dyn SystemClock>,
+
+    /// Archived `Period`s and their assignments
+    horizon_state: HorizonState,
+
+    /// The last changes in the order they were made. Read through a
+    /// `ChangeSubscription`.
+    change_log: ChangeLog,
 }
 
 /// Public methods
@@ -140,9 +224,28 @@ impl ScheduleGraph
             period_indices: HashMap::new(),
             skill_indices: HashMap::new(),
             day_indices: BTreeMap::new(),
+            skill_catalog: SkillCatalog::new(),
+            work_order_infos: HashMap::new(),
+            system_clock: Box::new(RealClock),
+            horizon_state: HorizonState::default(),
+            change_log: ChangeLog::default(),
         }
     }
 
+    pub fn with_system_clock(mut self, system_clock: impl SystemClock + 'static) -> Self
+    {
+        self.system_clock = Box::new(system_clock);
+        self
+    }
+
+    /// `Previous` `Period`s can not be modified. In `Frozen` `Period`s only
+    /// the assignments of `Activity`s to days may change.
+    pub fn period_state(&self, period: &Period) -> Result<PeriodState, ScheduleGraphErrors>
+    {
+        self.period_node_index(period)?;
+        Ok(period.period_state(self.system_clock.today()))
+    }
+
     pub(crate) fn nodes(&self) -> &[Node]
     {
         &self.nodes
@@ -158,6 +261,48 @@ impl ScheduleGraph
         &self.incidence_list
     }
 
+    pub(crate) fn horizon_state(&self) -> &HorizonState
+    {
+        &self.horizon_state
+    }
+
+    pub(crate) fn horizon_state_mut(&mut self) -> &mut HorizonState
+    {
+        &mut self.horizon_state
+    }
+
+    pub(crate) fn change_log(&self) -> &ChangeLog
+    {
+        &self.change_log
+    }
+
+    pub(crate) fn change_log_mut(&mut self) -> &mut ChangeLog
+    {
+        &mut self.change_log
+    }
+
+    pub(crate) fn work_order_node_index(&self, work_order_number: &WorkOrderNumber) -> Result<&NodeIndex, ScheduleGraphErrors>
+    {
+        self.work_order_indices
+            .get(work_order_number)
+            .ok_or(ScheduleGraphErrors::WorkOrderMissing)
+    }
+
+    pub fn work_order_info(&self, work_order_number: &WorkOrderNumber) -> Result<&WorkOrderInfo, ScheduleGraphErrors>
+    {
+        self.work_order_infos.get(work_order_number).ok_or(ScheduleGraphErrors::WorkOrderMissing)
+    }
+
+    pub(crate) fn period_node_index(&self, period: &Period) -> Result<&NodeIndex, ScheduleGraphErrors>
+    {
+        self.period_indices.get(period).ok_or(ScheduleGraphErrors::PeriodMissing)
+    }
+
+    pub(crate) fn day_node_index(&self, day: &NaiveDate) -> Result<&NodeIndex, ScheduleGraphErrors>
+    {
+        self.day_indices.get(day).ok_or(ScheduleGraphErrors::DayMissing)
+    }
+
     /// Returns the number of nodes in the graph
     pub fn node_count(&self) -> usize
     {
@@ -178,25 +323,59 @@ impl ScheduleGraph
 /// Public API to add [`Nodes`] to the graph.
 impl ScheduleGraph
 {
-    pub fn add_skill(&mut self, skill: Skill) -> NodeIndex
+    /// Only a `Skill` of the `SkillCatalog` of the graph can be added, see
+    /// `ScheduleGraph::add_skill_catalog`.
+    pub fn add_skill(&mut self, skill: Skill) -> Result<NodeIndex, ScheduleGraphErrors>
     {
+        if !self.skill_catalog.contains(&skill) {
+            return Err(ScheduleGraphErrors::SkillNotInCatalog);
+        }
         if let Some(&existing) = self.skill_indices.get(&skill) {
-            return existing;
+            return Ok(existing);
         }
-        self.add_node(Node::Skill(skill))
+        Ok(self.add_node(Node::Skill(skill)))
     }
 
+    /// Extends the `SkillCatalog` of the graph and adds every `Skill` of the
+    /// catalog that is not already in the graph
+    pub fn add_skill_catalog(&mut self, skill_catalog: &SkillCatalog) -> Vec<NodeIndex>
+    {
+        skill_catalog
+            .skills()
+            .map(|skill| {
+                self.skill_catalog.insert(skill.clone());
+                self.add_skill(skill.clone()).expect("The `Skill` was added to the `SkillCatalog`")
+            })
+            .collect()
+    }
+
+    /// The `WorkOrder` is checked again as a deserialized `WorkOrder` has not
+    /// been through `WorkOrder::new` and `WorkOrder::with_latest_finish`.
     pub fn add_work_order(&mut self, work_order: &WorkOrder) -> Result<NodeIndex, ScheduleGraphErrors>
     {
+        if !work_order.activities().iter().all(Activity::is_valid) {
+            return Err(ScheduleGraphErrors::WorkOrderActivityInvalidWork);
+        }
+        if work_order
+            .latest_finish()
+            .is_some_and(|latest_finish| latest_finish < work_order.basic_start())
+        {
+            return Err(ScheduleGraphErrors::WorkOrderLatestFinishBeforeBasicStart);
+        }
         if !work_order
             .activities()
             .iter()
-            .all(|activity| self.skill_indices.keys().any(|&all_skills| all_skills == activity.skill()))
+            .flat_map(|activity| activity.requirements())
+            .all(|requirement| self.skill_indices.contains_key(requirement.skill()))
         {
             return Err(ScheduleGraphErrors::WorkOrderActivityMissingSkills);
         }
 
         let day_node_index = *self.day_indices.get(&work_order.basic_start()).ok_or(ScheduleGraphErrors::DayMissing)?;
+        let latest_finish_day_node_index = work_order
+            .latest_finish()
+            .map(|latest_finish| self.day_indices.get(&latest_finish).copied().ok_or(ScheduleGraphErrors::DayMissing))
+            .transpose()?;
 
         // Crucial lesson! This cannot come first! You learned something great here!
         let work_order_node_index = match self.work_order_indices.entry(work_order.work_order_number()) {
@@ -205,6 +384,9 @@ impl ScheduleGraph
         };
 
         let _basic_start_edge_index = self.add_edge(EdgeType::BasicStart, vec![work_order_node_index, day_node_index]);
+        if let Some(latest_finish_day_node_index) = latest_finish_day_node_index {
+            self.add_edge(EdgeType::LatestFinish, vec![work_order_node_index, latest_finish_day_node_index]);
+        }
 
         let mut previous_activity_node = usize::MAX;
         let activity_relations = work_order.activities_relations();
@@ -212,11 +394,18 @@ impl ScheduleGraph
             let activity_node_index = self.add_node(Node::Activity(ActivityNode {
                 activity_number: activity.activity_number(),
                 number_of_people: activity.number_of_people(),
+                work: activity.work(),
+                duration: activity.duration(),
             }));
-            let skill_node_index = *self.skill_indices.get(&activity.skill()).ok_or(ScheduleGraphErrors::SkillMissing)?;
 
             self.add_edge(EdgeType::Contains, vec![work_order_node_index, activity_node_index]);
-            self.add_edge(EdgeType::Requires, vec![activity_node_index, skill_node_index]);
+            for requirement in activity.requirements() {
+                let skill_node_index = *self.skill_indices.get(requirement.skill()).ok_or(ScheduleGraphErrors::SkillMissing)?;
+                self.add_edge(
+                    EdgeType::Requires(requirement.number_of_people(), requirement.minimum_proficiency()),
+                    vec![activity_node_index, skill_node_index],
+                );
+            }
 
             if activity_index != 0 {
                 match activity_relations[activity_index - 1] {
@@ -231,6 +420,8 @@ impl ScheduleGraph
         // TODO [x] - add relationships between activities here.
 
         self.work_order_indices.insert(work_order.work_order_number(), work_order_node_index);
+        self.work_order_infos
+            .insert(work_order.work_order_number(), *work_order.work_order_info());
         Ok(work_order_node_index)
     }
 
@@ -240,7 +431,9 @@ impl ScheduleGraph
             return Err(ScheduleGraphErrors::PeriodDuplicate);
         };
 
-        let days_in_period = (0..14).map(|e| period.start_date() + chrono::Days::new(e)).collect::<Vec<_>>();
+        let days_in_period = (0..Period::NUMBER_OF_DAYS)
+            .map(|e| period.start_date() + chrono::Days::new(e))
+            .collect::<Vec<_>>();
 
         for day in days_in_period {
             let day_node = self.add_node(Node::Day(day));
@@ -279,9 +472,11 @@ impl ScheduleGraph
         }
 
         let mut skills = vec![];
-        for skill in technician.skills() {
+        let mut qualifications = vec![];
+        for (skill, qualification) in technician.qualifications() {
             let skill = *self.skill_indices.get(skill).ok_or(ScheduleGraphErrors::SkillMissing)?;
             skills.push(skill);
+            qualifications.push((skill, *qualification));
         }
 
         // You have to check and create all the availabilities and then
@@ -305,7 +500,11 @@ impl ScheduleGraph
         edges.extend(skills);
         edges.extend(single_availability);
 
-        let availability_edge = self.add_edge(EdgeType::Available, edges);
+        let availability_edge = self.add_edge(EdgeType::Available(availability), edges);
+
+        for (skill, qualification) in qualifications {
+            self.add_edge(EdgeType::HasSkill(qualification), vec![technician_id, skill]);
+        }
 
         Ok(availability_edge)
     }
@@ -326,15 +525,281 @@ impl ScheduleGraph
         // This should return an error if the `Nodes` is not present.
         let worker = self.technician_indices.get(&worker).ok_or(ScheduleGraphErrors::WorkerMissing)?;
         let work_order = self.work_order_indices.get(&work_order).ok_or(ScheduleGraphErrors::WorkOrderMissing)?;
-        let date = self.period_indices.get(&date).ok_or(ScheduleGraphErrors::PeriodMissing)?;
+        let period_node_index = self.period_indices.get(&date).ok_or(ScheduleGraphErrors::PeriodMissing)?;
+        self.check_period_open(&date)?;
 
-        let hyperedge = HyperEdge {
-            edge_type: EdgeType::Assign(None),
-            nodes: vec![*worker, *work_order, *date],
-        };
+        Ok(self.add_edge(
+            EdgeType::Assign(None, LockState::Unlocked),
+            vec![*worker, *work_order, *period_node_index],
+        ))
+    }
+
+    /// Assigns the `WorkOrder` to a `Period` without any `Technician`. This is
+    /// how the strategic plan is stored in the graph.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]`
+    pub fn add_assignment_work_order_period(&mut self, work_order_number: &WorkOrderNumber, period: &Period)
+    -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+        let period_node_index = *self.period_node_index(period)?;
+        self.check_period_open(period)?;
+
+        Ok(self.add_edge(
+            EdgeType::Assign(None, LockState::Unlocked),
+            vec![work_order_node_index, period_node_index],
+        ))
+    }
+
+    /// Removes every assignment of the `WorkOrder` to a `Period` and returns
+    /// the `Period`s that it was assigned to. The assignments of
+    /// `Technician`s to the `WorkOrder` and the `Activity` assignments are
+    /// left untouched.
+    ///
+    /// Nothing is removed when one of the assignments is locked.
+    ///
+    /// Removing hyperedges invalidates previously returned `EdgeIndex`s.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]`
+    pub fn remove_assignments_work_order(&mut self, work_order_number: &WorkOrderNumber) -> Result<Vec<Period>, ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        let mut assignment_edge_indices = self.incidence_list[work_order_node_index]
+            .iter()
+            .copied()
+            .filter(|&edge_index| {
+                let hyperedge = &self.hyperedges[edge_index];
+                matches!(hyperedge.edge_type, EdgeType::Assign(None, _))
+                    && matches!(hyperedge.nodes[..], [_, period_node_index] if matches!(self.nodes[period_node_index], Node::Period(_)))
+            })
+            .collect::<Vec<_>>();
+        if assignment_edge_indices
+            .iter()
+            .any(|&edge_index| matches!(self.hyperedges[edge_index].edge_type, EdgeType::Assign(_, LockState::Locked)))
+        {
+            return Err(ScheduleGraphErrors::AssignmentLocked);
+        }
+        for &edge_index in &assignment_edge_indices {
+            for &node_index in &self.hyperedges[edge_index].nodes {
+                if let Node::Period(period) = self.nodes[node_index] {
+                    self.check_period_open(&period)?;
+                }
+            }
+        }
+        // Removing the largest `EdgeIndex` first keeps the remaining ones valid.
+        assignment_edge_indices.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut periods = vec![];
+        for edge_index in assignment_edge_indices {
+            let hyperedge = self.remove_edge(edge_index);
+            periods.extend(hyperedge.nodes.iter().filter_map(|&node_index| match self.nodes[node_index] {
+                Node::Period(period) => Some(period),
+                _ => None,
+            }));
+        }
+        Ok(periods)
+    }
+
+    /// Moves the `WorkOrder` into the `Period`, or unassigns it when the
+    /// `Period` is `None`, and returns the `Period`s that it was assigned to.
+    /// The `Technician`s that are assigned to the `WorkOrder` are moved with
+    /// it. The `Activity` assignments are left untouched.
+    ///
+    /// Nothing is changed when `ScheduleGraph::check_move_work_order` fails.
+    ///
+    /// Removing hyperedges invalidates previously returned `EdgeIndex`s.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]` and `vec![$technician, $work_order,
+    /// $period]`
+    pub fn move_work_order(&mut self, work_order_number: &WorkOrderNumber, period: Option<&Period>) -> Result<Vec<Period>, ScheduleGraphErrors>
+    {
+        self.check_move_work_order(work_order_number, period)?;
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+
+        let mut assignment_edge_indices = self.work_order_period_edges(work_order_node_index);
+        let mut technician_node_indices = assignment_edge_indices
+            .iter()
+            .filter_map(|&edge_index| match self.hyperedges[edge_index].nodes[..] {
+                [technician_node_index, _, _] => Some(technician_node_index),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        technician_node_indices.sort();
+        technician_node_indices.dedup();
+
+        // Removing the largest `EdgeIndex` first keeps the remaining ones valid.
+        assignment_edge_indices.sort_unstable_by(|a, b| b.cmp(a));
+        let mut periods = vec![];
+        for edge_index in assignment_edge_indices {
+            let hyperedge = self.remove_edge(edge_index);
+            periods.extend(hyperedge.nodes.iter().filter_map(|&node_index| match self.nodes[node_index] {
+                Node::Period(period) => Some(period),
+                _ => None,
+            }));
+        }
+        periods.sort();
+        periods.dedup();
+
+        if let Some(period) = period {
+            let period_node_index = *self.period_node_index(period)?;
+            self.add_edge(
+                EdgeType::Assign(None, LockState::Unlocked),
+                vec![work_order_node_index, period_node_index],
+            );
+            for technician_node_index in technician_node_indices {
+                self.add_edge(
+                    EdgeType::Assign(None, LockState::Unlocked),
+                    vec![technician_node_index, work_order_node_index, period_node_index],
+                );
+            }
+        }
+        Ok(periods)
+    }
+
+    /// Checks that `ScheduleGraph::move_work_order` can move the `WorkOrder`
+    /// into the `Period` without changing the graph. None of the assignments
+    /// of the `WorkOrder` to a `Period` may be locked and both the old and the
+    /// new `Period`s have to be open.
+    pub fn check_move_work_order(&self, work_order_number: &WorkOrderNumber, period: Option<&Period>) -> Result<(), ScheduleGraphErrors>
+    {
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+        if let Some(period) = period {
+            self.period_node_index(period)?;
+            self.check_period_open(period)?;
+        }
+
+        for edge_index in self.work_order_period_edges(work_order_node_index) {
+            let hyperedge = &self.hyperedges[edge_index];
+            if matches!(hyperedge.edge_type, EdgeType::Assign(_, LockState::Locked)) {
+                return Err(ScheduleGraphErrors::AssignmentLocked);
+            }
+            for &node_index in &hyperedge.nodes {
+                if let Node::Period(period) = self.nodes[node_index] {
+                    self.check_period_open(&period)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Moves the `WorkOrder` into the `Period` and locks the assignment so
+    /// that the planning keeps it there.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]`
+    pub fn freeze_work_order(&mut self, work_order_number: &WorkOrderNumber, period: &Period) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let period_node_index = *self.period_node_index(period)?;
+        let work_order_node_index = *self.work_order_node_index(work_order_number)?;
+        self.check_period_open(period)?;
+
+        if let Some(&edge_index) = self.incidence_list[work_order_node_index].iter().find(|&&edge_index| {
+            let hyperedge = &self.hyperedges[edge_index];
+            hyperedge.edge_type == EdgeType::Assign(None, LockState::Locked) && hyperedge.nodes.contains(&period_node_index)
+        }) {
+            return Ok(edge_index);
+        }
 
-        self.hyperedges.push(hyperedge);
-        Ok(self.hyperedges.len() - 1)
+        self.remove_assignments_work_order(work_order_number)?;
+        Ok(self.add_edge(EdgeType::Assign(None, LockState::Locked), vec![work_order_node_index, period_node_index]))
+    }
+
+    /// Locks every assignment of `WorkOrder`s to the `Period` and of
+    /// `Activity`s to the days of the `Period`. The `Period` is locked as a
+    /// whole, see `ScheduleGraph::locked_periods`.
+    pub fn freeze_period(&mut self, period: &Period) -> Result<(), ScheduleGraphErrors>
+    {
+        if self.period_state(period)? == PeriodState::Previous {
+            return Err(ScheduleGraphErrors::PeriodPrevious);
+        }
+        for edge_index in self.find_all_assignments_for_period(*period)? {
+            if let EdgeType::Assign(_, lock_state) = &mut self.hyperedges[edge_index].edge_type {
+                *lock_state = LockState::Locked;
+            }
+            self.record_hyperedge_changes(&self.hyperedges[edge_index].nodes.clone());
+        }
+        if self.horizon_state.frozen_periods.insert(*period) {
+            self.record_change(GraphChange::Period(*period));
+        }
+        Ok(())
+    }
+
+    /// Plans the `Activity` on `days` without a crew. This is how the
+    /// tactical plan is stored in the graph. The `Technician`s are added
+    /// later by `ScheduleGraph::add_assignment_activity`.
+    ///
+    /// Format
+    /// `vec![$activity, @days]`
+    pub fn add_assignment_activity_days(
+        &mut self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+        days: Vec<NaiveDate>,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        let mut day_node_indices = vec![];
+        for day in &days {
+            day_node_indices.push(*self.day_indices.get(day).ok_or(ScheduleGraphErrors::DayMissing)?);
+            self.check_day_not_previous(day)?;
+        }
+
+        let mut nodes = vec![activity_node_index];
+        nodes.extend(day_node_indices);
+        Ok(self.add_edge(EdgeType::Assign(None, LockState::Unlocked), nodes))
+    }
+
+    /// Removes the days that the `Activity` is planned on and returns them.
+    /// The assignments of `Technician`s to the `Activity` are left untouched.
+    ///
+    /// Nothing is removed when one of the assignments is locked or lies in a
+    /// `Previous` `Period`.
+    ///
+    /// Removing hyperedges invalidates previously returned `EdgeIndex`s.
+    pub fn remove_assignment_activity_days(
+        &mut self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<Vec<NaiveDate>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        let mut assignment_edge_indices = self.incidence_list[activity_node_index]
+            .iter()
+            .copied()
+            .filter(|&edge_index| matches!(self.hyperedges[edge_index].edge_type, EdgeType::Assign(None, _)))
+            .collect::<Vec<_>>();
+        if assignment_edge_indices
+            .iter()
+            .any(|&edge_index| matches!(self.hyperedges[edge_index].edge_type, EdgeType::Assign(_, LockState::Locked)))
+        {
+            return Err(ScheduleGraphErrors::AssignmentLocked);
+        }
+        for &edge_index in &assignment_edge_indices {
+            for &node_index in &self.hyperedges[edge_index].nodes {
+                if let Node::Day(day) = self.nodes[node_index] {
+                    self.check_day_not_previous(&day)?;
+                }
+            }
+        }
+        // Removing the largest `EdgeIndex` first keeps the remaining ones valid.
+        assignment_edge_indices.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut days = vec![];
+        for edge_index in assignment_edge_indices {
+            let hyperedge = self.remove_edge(edge_index);
+            days.extend(hyperedge.nodes.iter().filter_map(|&node_index| match self.nodes[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            }));
+        }
+        days.sort();
+        Ok(days)
     }
 
     /// Format
@@ -355,38 +820,18 @@ impl ScheduleGraph
         let mut date_node_indices = vec![];
         for naive_date in &days {
             date_node_indices.push(self.day_indices.get(naive_date).ok_or(ScheduleGraphErrors::DayMissing)?);
+            self.check_day_not_previous(naive_date)?;
         }
 
         let mut technician_node_indices = vec![];
-        'technician: for technician_id in &technicians {
+        for technician_id in &technicians {
             let technician_node_index = self.technician_indices.get(technician_id).ok_or(ScheduleGraphErrors::WorkerMissing)?;
             technician_node_indices.push(technician_node_index);
 
-            for availability_hyperedge in self.incidence_list[*technician_node_index]
-                .iter()
-                .filter(|&&hyperedge_index| matches!(self.hyperedges[hyperedge_index].edge_type, EdgeType::Available))
-            {
-                match self.hyperedges[*availability_hyperedge].edge_type {
-                    // You have to cover the shift with days. That is the most fundamental here.
-                    EdgeType::Available => {
-                        let availability_nodes = &self.hyperedges[*availability_hyperedge].nodes;
-
-                        let availability_days = availability_nodes
-                            .iter()
-                            .filter_map(|node_index| match &self.nodes[*node_index] {
-                                Node::Day(naive_date) => Some(naive_date),
-                                _ => None,
-                            })
-                            .collect::<Vec<_>>();
-
-                        if days.iter().all(|activity_day| availability_days.contains(&activity_day)) {
-                            continue 'technician;
-                        };
-                    }
-                    _ => unreachable!(),
-                }
+            // You have to cover the shift with days. That is the most fundamental here.
+            if !self.is_technician_available(*technician_node_index, &days) {
+                return Err(ScheduleGraphErrors::WorkerUnavailable);
             }
-            return Err(ScheduleGraphErrors::WorkerUnavailable);
         }
 
         // TODO [ ] - Find the availabilities for every technician and make sure that
@@ -413,10 +858,28 @@ impl ScheduleGraph
             })
             .ok_or(ScheduleGraphErrors::ActivityMissing)?;
 
-        if let Node::Activity(activity) = &self.nodes[*activity_node_index]
-            && technicians.len() > activity.number_of_people as usize
-        {
-            return Err(ScheduleGraphErrors::ActivityExceedNumberOfPeople);
+        if let Node::Activity(activity) = &self.nodes[*activity_node_index] {
+            let technician_node_indices = technician_node_indices.iter().map(|&&node_index| node_index).collect::<Vec<_>>();
+            // The crews of the earlier assignments on the same days count
+            // towards the number of people as well.
+            let day_crews = self.activity_day_crews(*activity_node_index);
+            for day in &days {
+                let mut day_crew = day_crews.get(day).cloned().unwrap_or_default();
+                day_crew.extend(&technician_node_indices);
+                if day_crew.len() > activity.number_of_people as usize {
+                    return Err(ScheduleGraphErrors::ActivityExceedNumberOfPeople);
+                }
+            }
+
+            self.check_crew_fits_requirements(&technician_node_indices, *activity_node_index, &days)?;
+
+            // The crew can never be booked for more than the planned work of the
+            // `Activity`.
+            let (start_time, finish_time) = start_and_finish_time;
+            let requested_work = (finish_time - start_time) * (technicians.len() * days.len()) as i32;
+            if self.assigned_work(*activity_node_index) + requested_work > activity.work {
+                return Err(ScheduleGraphErrors::ActivityExceedWork);
+            }
         }
 
         let mut final_nodes_in_hyperedge = vec![*activity_node_index];
@@ -424,7 +887,95 @@ impl ScheduleGraph
         final_nodes_in_hyperedge.extend(date_node_indices);
 
         // TODO [ ] - Add `Day`s as well.
-        Ok(self.add_edge(EdgeType::Assign(Some(start_and_finish_time)), final_nodes_in_hyperedge))
+        Ok(self.add_edge(
+            EdgeType::Assign(Some(start_and_finish_time), LockState::Unlocked),
+            final_nodes_in_hyperedge,
+        ))
+    }
+
+    /// Moves the assignment of the `Technician` to the `Activity` on the
+    /// `day` to a new time window. The other `Technician`s and `Day`s of the
+    /// same assignment keep their time window, so the hyperedge is split
+    /// when it holds more than the one `Technician` and `Day`.
+    ///
+    /// Removing hyperedges invalidates previously returned `EdgeIndex`s.
+    ///
+    /// Format
+    /// `vec![$activity, @technicians, @days]`
+    pub fn retime_assignment_activity(
+        &mut self,
+        technician_id: TechnicianId,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+        day: NaiveDate,
+        start_and_finish_time: (StartTime, FinishTime),
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let technician_node_index = *self.technician_node_index(&technician_id)?;
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+        let day_node_index = *self.day_indices.get(&day).ok_or(ScheduleGraphErrors::DayMissing)?;
+        self.check_day_not_previous(&day)?;
+
+        let edge_index = *self.incidence_list[activity_node_index]
+            .iter()
+            .find(|&&edge_index| {
+                let hyperedge = &self.hyperedges[edge_index];
+                matches!(hyperedge.edge_type, EdgeType::Assign(Some(_), _))
+                    && hyperedge.nodes.contains(&technician_node_index)
+                    && hyperedge.nodes.contains(&day_node_index)
+            })
+            .ok_or(ScheduleGraphErrors::AssignmentMissing)?;
+        let EdgeType::Assign(Some(previous_start_and_finish_time), lock_state) = self.hyperedges[edge_index].edge_type else {
+            unreachable!()
+        };
+        if lock_state == LockState::Locked {
+            return Err(ScheduleGraphErrors::AssignmentLocked);
+        }
+        if previous_start_and_finish_time == start_and_finish_time {
+            return Ok(edge_index);
+        }
+
+        let Node::Activity(activity) = &self.nodes[activity_node_index] else {
+            unreachable!()
+        };
+        let (previous_start_time, previous_finish_time) = previous_start_and_finish_time;
+        let (start_time, finish_time) = start_and_finish_time;
+        if self.assigned_work(activity_node_index) - (previous_finish_time - previous_start_time) + (finish_time - start_time) > activity.work {
+            return Err(ScheduleGraphErrors::ActivityExceedWork);
+        }
+
+        let hyperedge = self.remove_edge(edge_index);
+        let (technician_node_indices, day_node_indices): (Vec<_>, Vec<_>) = hyperedge.nodes[1..]
+            .iter()
+            .partition(|&&node_index| matches!(self.nodes[node_index], Node::Technician(_)));
+
+        let other_technician_node_indices = technician_node_indices
+            .iter()
+            .copied()
+            .filter(|&node_index| node_index != technician_node_index)
+            .collect::<Vec<_>>();
+        if !other_technician_node_indices.is_empty() {
+            let mut nodes = vec![activity_node_index];
+            nodes.extend(other_technician_node_indices);
+            nodes.extend(&day_node_indices);
+            self.add_edge(EdgeType::Assign(Some(previous_start_and_finish_time), LockState::Unlocked), nodes);
+        }
+
+        let other_day_node_indices = day_node_indices
+            .iter()
+            .copied()
+            .filter(|&node_index| node_index != day_node_index)
+            .collect::<Vec<_>>();
+        if !other_day_node_indices.is_empty() {
+            let mut nodes = vec![activity_node_index, technician_node_index];
+            nodes.extend(other_day_node_indices);
+            self.add_edge(EdgeType::Assign(Some(previous_start_and_finish_time), LockState::Unlocked), nodes);
+        }
+
+        Ok(self.add_edge(
+            EdgeType::Assign(Some(start_and_finish_time), LockState::Unlocked),
+            vec![activity_node_index, technician_node_index, day_node_index],
+        ))
     }
 
     // This function should be in a different place in the code. I believe that
@@ -444,23 +995,15 @@ impl ScheduleGraph
             .hyperedges
             .iter()
             .enumerate()
-            .filter(|e| matches!(e.1.edge_type, EdgeType::Assign(_)))
+            .filter(|e| matches!(e.1.edge_type, EdgeType::Assign(..)))
             .collect::<Vec<_>>();
 
         let mut edges = vec![];
         for (edge_index, hyper_edge) in &assignment_hyper_edges {
             for nodes in &hyper_edge.nodes {
                 match self.nodes[*nodes] {
-                    Node::Period(period) => {
-                        if period == period_start_date {
-                            edges.push(*edge_index)
-                        }
-                    }
-                    Node::Day(naive_date) => {
-                        if period_start_date.start_date() <= naive_date && naive_date < (period_start_date.start_date() + Duration::days(13)) {
-                            edges.push(*edge_index)
-                        }
-                    }
+                    Node::Period(period) if period == period_start_date => edges.push(*edge_index),
+                    Node::Day(naive_date) if period_start_date.contains(naive_date) => edges.push(*edge_index),
                     // We are only interested in the time of the assignment. `Worker` and `WorkOrder` belong
                     // in a different method.
                     _ => (),
@@ -468,15 +1011,22 @@ impl ScheduleGraph
             }
         }
 
+        // An assignment spanning several days is only returned once
+        edges.dedup();
         Ok(edges)
     }
 
-    pub fn add_assign_skill_to_worker(&mut self, worker: TechnicianId, skill: Skill) -> Result<EdgeIndex, ScheduleGraphErrors>
+    pub fn add_assign_skill_to_worker(
+        &mut self,
+        worker: TechnicianId,
+        skill: Skill,
+        qualification: Qualification,
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
     {
         let worker = self.technician_indices.get(&worker).ok_or(ScheduleGraphErrors::WorkerMissing)?;
         let skill = self.skill_indices.get(&skill).ok_or(ScheduleGraphErrors::SkillMissing)?;
 
-        Ok(self.add_edge(EdgeType::HasSkill, vec![*worker, *skill]))
+        Ok(self.add_edge(EdgeType::HasSkill(qualification), vec![*worker, *skill]))
     }
 
     /// This method can fail when:
@@ -492,6 +1042,7 @@ impl ScheduleGraph
             .get(work_order_number)
             .ok_or(ScheduleGraphErrors::WorkOrderMissing)?;
         let period_node_index = self.period_indices.get(period).ok_or(ScheduleGraphErrors::PeriodMissing)?;
+        self.check_period_open(period)?;
 
         let days_node_indices = self
             .day_indices
@@ -527,7 +1078,7 @@ impl ScheduleGraph
             Node::Technician(worker) => self.technician_indices.insert(worker, node_index),
             Node::WorkOrder(work_order) => self.work_order_indices.insert(work_order, node_index),
             Node::Period(naive_date) => self.period_indices.insert(naive_date, node_index),
-            Node::Skill(skills) => self.skill_indices.insert(skills, node_index),
+            Node::Skill(ref skills) => self.skill_indices.insert(skills.clone(), node_index),
             Node::Activity(ref a) => {
                 debug!(target: "developer", activity = ?a, "No node index for `Activities`");
                 None
@@ -538,6 +1089,13 @@ impl ScheduleGraph
 
         self.incidence_list.push(vec![]);
 
+        match node {
+            Node::Technician(technician_id) => self.record_change(GraphChange::Technician(technician_id)),
+            Node::WorkOrder(work_order_number) => self.record_change(GraphChange::WorkOrder(work_order_number)),
+            Node::Period(period) => self.record_change(GraphChange::Period(period)),
+            Node::Activity(_) | Node::Skill(_) | Node::Day(_) => (),
+        }
+
         // node is added `Vec<Nodes>`
         self.nodes.push(node);
         node_index
@@ -552,9 +1110,306 @@ impl ScheduleGraph
         }
         let hyper_edge = HyperEdge { edge_type, nodes };
         self.hyperedges.push(hyper_edge);
+        self.record_hyperedge_changes(&self.hyperedges[edge_index].nodes.clone());
         edge_index
     }
+
+    /// Every assignment of the `WorkOrder` to a `Period`, with or without a
+    /// `Technician`.
+    ///
+    /// Format
+    /// `vec![$work_order, $period]` and `vec![$technician, $work_order,
+    /// $period]`
+    fn work_order_period_edges(&self, work_order_node_index: NodeIndex) -> Vec<EdgeIndex>
+    {
+        self.incidence_list[work_order_node_index]
+            .iter()
+            .copied()
+            .filter(|&edge_index| {
+                let hyperedge = &self.hyperedges[edge_index];
+                matches!(hyperedge.edge_type, EdgeType::Assign(None, _))
+                    && hyperedge
+                        .nodes
+                        .last()
+                        .is_some_and(|&node_index| matches!(self.nodes[node_index], Node::Period(_)))
+            })
+            .collect()
+    }
+
+    /// The `WorkOrder`s of a `Period` may only be changed while it is a draft.
+    fn check_period_open(&self, period: &Period) -> Result<(), ScheduleGraphErrors>
+    {
+        match period.period_state(self.system_clock.today()) {
+            PeriodState::Previous => Err(ScheduleGraphErrors::PeriodPrevious),
+            PeriodState::Frozen => Err(ScheduleGraphErrors::PeriodFrozen),
+            PeriodState::Draft | PeriodState::Draft2 => Ok(()),
+        }
+    }
+
+    /// The assignments on the days of a `Previous` `Period` may not be
+    /// changed. The archived `Period`s are a part of the check as they are
+    /// left out of `ScheduleGraph::periods`.
+    pub(crate) fn check_day_not_previous(&self, day: &NaiveDate) -> Result<(), ScheduleGraphErrors>
+    {
+        if self
+            .period_indices
+            .keys()
+            .any(|period| period.contains(*day) && period.period_state(self.system_clock.today()) == PeriodState::Previous)
+        {
+            return Err(ScheduleGraphErrors::PeriodPrevious);
+        }
+        Ok(())
+    }
+
+    /// The last hyperedge takes the place of the removed one so that the
+    /// `EdgeIndex`s stay dense. Its entries in the incidence list are updated
+    /// accordingly.
+    pub(crate) fn remove_edge(&mut self, edge_index: EdgeIndex) -> HyperEdge
+    {
+        let last_edge_index = self.hyperedges.len() - 1;
+        self.record_hyperedge_changes(&self.hyperedges[edge_index].nodes.clone());
+
+        let hyperedge = self.hyperedges.swap_remove(edge_index);
+        for node_index in &hyperedge.nodes {
+            self.incidence_list[*node_index].retain(|&incident_edge_index| incident_edge_index != edge_index);
+        }
+
+        if edge_index != last_edge_index {
+            for node_index in &self.hyperedges[edge_index].nodes {
+                for incident_edge_index in &mut self.incidence_list[*node_index] {
+                    if *incident_edge_index == last_edge_index {
+                        *incident_edge_index = edge_index;
+                    }
+                }
+            }
+        }
+        hyperedge
+    }
+
+    /// Every `Technician` of the crew has to fill a separate place in one of
+    /// the `EdgeType::Requires` of the `Activity`. A crew that is smaller than
+    /// the requirements is a partial staffing and is accepted. On every day
+    /// the places are shared with the `Technician`s that earlier assignments
+    /// put on the `Activity`.
+    fn check_crew_fits_requirements(
+        &self,
+        technician_node_indices: &[NodeIndex],
+        activity_node_index: NodeIndex,
+        days: &[NaiveDate],
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        // Format: `vec![$activity, $skill]`
+        let requirements = self.incidence_list[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges[edge_index])
+            .filter_map(|hyperedge| match hyperedge.edge_type {
+                EdgeType::Requires(number_of_people, minimum_proficiency) => Some((hyperedge.nodes[1], number_of_people, minimum_proficiency)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut eligible_requirements = vec![];
+        for &technician_node_index in technician_node_indices {
+            let mut eligible = vec![];
+            let mut most_specific_error = ScheduleGraphErrors::WorkerMissingSkill;
+            for (requirement_index, &(skill_node_index, _, minimum_proficiency)) in requirements.iter().enumerate() {
+                match self.check_technician_qualified(technician_node_index, skill_node_index, minimum_proficiency, days) {
+                    Ok(()) => eligible.push(requirement_index),
+                    // The errors are declared from the least to the most specific
+                    Err(error) => most_specific_error = most_specific_error.max(error),
+                }
+            }
+
+            if eligible.is_empty() {
+                return Err(most_specific_error);
+            }
+            eligible_requirements.push(eligible);
+        }
+
+        let quotas = requirements.iter().map(|&(_, number_of_people, _)| number_of_people).collect::<Vec<_>>();
+        if !crew_fits_quotas(&eligible_requirements, &quotas) {
+            return Err(ScheduleGraphErrors::ActivityExceedSkillRequirement);
+        }
+
+        let day_crews = self.activity_day_crews(activity_node_index);
+        for day in days {
+            let Some(day_crew) = day_crews.get(day) else {
+                continue;
+            };
+            let mut day_eligible_requirements = eligible_requirements.clone();
+            for &technician_node_index in day_crew.difference(&technician_node_indices.iter().copied().collect()) {
+                day_eligible_requirements.push(
+                    requirements
+                        .iter()
+                        .enumerate()
+                        .filter(|&(_, &(skill_node_index, _, minimum_proficiency))| {
+                            self.check_technician_qualified(technician_node_index, skill_node_index, minimum_proficiency, &[*day])
+                                .is_ok()
+                        })
+                        .map(|(requirement_index, _)| requirement_index)
+                        .collect(),
+                );
+            }
+            if !crew_fits_quotas(&day_eligible_requirements, &quotas) {
+                return Err(ScheduleGraphErrors::ActivityExceedSkillRequirement);
+            }
+        }
+        Ok(())
+    }
+
+    /// Format
+    /// `vec![$technician, @skills, @days]`
+    pub(crate) fn is_technician_available(&self, technician_node_index: NodeIndex, days: &[NaiveDate]) -> bool
+    {
+        self.incidence_list[technician_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type, EdgeType::Available(_)))
+            .any(|hyperedge| {
+                days.iter()
+                    .all(|day| hyperedge.nodes.iter().any(|&node_index| self.nodes[node_index] == Node::Day(*day)))
+            })
+    }
+
+    /// A `Technician` can work on an `Activity` when they are qualified for
+    /// at least one of its `EdgeType::Requires` on every one of the `days`.
+    pub(crate) fn is_technician_qualified_for_activity(
+        &self,
+        technician_node_index: NodeIndex,
+        activity_node_index: NodeIndex,
+        days: &[NaiveDate],
+    ) -> bool
+    {
+        // Format: `vec![$activity, $skill]`
+        self.incidence_list[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges[edge_index])
+            .any(|hyperedge| match hyperedge.edge_type {
+                EdgeType::Requires(_, minimum_proficiency) => self
+                    .check_technician_qualified(technician_node_index, hyperedge.nodes[1], minimum_proficiency, days)
+                    .is_ok(),
+                _ => false,
+            })
+    }
+
+    pub(crate) fn technician_node_index(&self, technician_id: &TechnicianId) -> Result<&NodeIndex, ScheduleGraphErrors>
+    {
+        self.technician_indices.get(technician_id).ok_or(ScheduleGraphErrors::WorkerMissing)
+    }
+
+    /// A `Technician` has to hold the `Skill` at the required `Proficiency`
+    /// with a `Qualification` that is valid on every one of the `days`.
+    fn check_technician_qualified(
+        &self,
+        technician_node_index: NodeIndex,
+        skill_node_index: NodeIndex,
+        minimum_proficiency: Proficiency,
+        days: &[NaiveDate],
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        let qualifications = self
+            .technician_qualifications(technician_node_index, skill_node_index)
+            .collect::<Vec<_>>();
+        if qualifications.is_empty() {
+            return Err(ScheduleGraphErrors::WorkerMissingSkill);
+        }
+
+        let proficient_qualifications = qualifications
+            .into_iter()
+            .filter(|qualification| qualification.proficiency() >= minimum_proficiency)
+            .collect::<Vec<_>>();
+        if proficient_qualifications.is_empty() {
+            return Err(ScheduleGraphErrors::WorkerInsufficientProficiency);
+        }
+
+        if !proficient_qualifications
+            .iter()
+            .any(|qualification| days.iter().all(|&day| qualification.is_valid_on(day)))
+        {
+            return Err(ScheduleGraphErrors::WorkerQualificationExpired);
+        }
+        Ok(())
+    }
+
+    /// Whether the `Technician` holds the `Skill` with a `Qualification`
+    /// that is valid on the `day`.
+    pub(crate) fn is_technician_qualified_on(&self, technician_node_index: NodeIndex, skill_node_index: NodeIndex, day: NaiveDate) -> bool
+    {
+        self.technician_qualifications(technician_node_index, skill_node_index)
+            .any(|qualification| qualification.is_valid_on(day))
+    }
+
+    /// Format
+    /// `vec![$technician, $skill]`
+    fn technician_qualifications(&self, technician_node_index: NodeIndex, skill_node_index: NodeIndex) -> impl Iterator<Item = Qualification> + '_
+    {
+        self.incidence_list[technician_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges[edge_index])
+            .filter_map(move |hyperedge| match hyperedge.edge_type {
+                EdgeType::HasSkill(qualification) if hyperedge.nodes[1] == skill_node_index => Some(qualification),
+                _ => None,
+            })
+    }
+
+    /// Total crew time booked on an `Activity` by `EdgeType::Assign`
+    /// hyperedges.
+    fn assigned_work(&self, activity_node_index: NodeIndex) -> TimeDelta
+    {
+        self.incidence_list[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges[edge_index])
+            .filter_map(|hyperedge| match hyperedge.edge_type {
+                EdgeType::Assign(Some((start_time, finish_time)), _) => {
+                    let (technicians, days) = hyperedge
+                        .nodes
+                        .iter()
+                        .fold((0, 0), |(technicians, days), &node_index| match self.nodes[node_index] {
+                            Node::Technician(_) => (technicians + 1, days),
+                            Node::Day(_) => (technicians, days + 1),
+                            _ => (technicians, days),
+                        });
+                    Some((finish_time - start_time) * technicians * days)
+                }
+                _ => None,
+            })
+            .sum()
+    }
 }
+/// Bipartite matching of technicians onto the places of the requirements.
+/// `eligible_requirements[technician]` lists the requirements that the
+/// technician is qualified for and `quotas[requirement]` the number of places.
+fn crew_fits_quotas(eligible_requirements: &[Vec<usize>], quotas: &[NumberOfPeople]) -> bool
+{
+    fn augment(technician: usize, eligible_requirements: &[Vec<usize>], places: &[usize], visited: &mut [bool], holders: &mut [Option<usize>])
+    -> bool
+    {
+        for (place, &requirement) in places.iter().enumerate() {
+            if visited[place] || !eligible_requirements[technician].contains(&requirement) {
+                continue;
+            }
+            visited[place] = true;
+            if holders[place].is_none_or(|holder| augment(holder, eligible_requirements, places, visited, holders)) {
+                holders[place] = Some(technician);
+                return true;
+            }
+        }
+        false
+    }
+
+    let places = quotas
+        .iter()
+        .enumerate()
+        .flat_map(|(requirement, &quota)| std::iter::repeat_n(requirement, quota as usize))
+        .collect::<Vec<_>>();
+    let mut holders = vec![None; places.len()];
+
+    (0..eligible_requirements.len()).all(|technician| {
+        let mut visited = vec![false; places.len()];
+        augment(technician, eligible_requirements, &places, &mut visited, &mut holders)
+    })
+}
+
 impl Default for ScheduleGraph
 {
     fn default() -> Self
@@ -571,23 +1426,39 @@ mod tests
     use chrono::Duration;
     use chrono::NaiveDate;
     use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::system_clock::MockClock;
     use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Proficiency;
+    use scheduling_environment::technician::Qualification;
     use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
     use scheduling_environment::technician::Technician;
     use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::SkillRequirement;
     use scheduling_environment::work_order::WorkOrder;
 
     use super::HyperEdge;
     use super::Node;
     use super::ScheduleGraph;
     use crate::schedule_graph::EdgeType;
+    use crate::schedule_graph::LockState;
     use crate::schedule_graph::Period;
+    use crate::schedule_graph::PeriodState;
     use crate::schedule_graph::ScheduleGraphErrors;
+    use crate::staffing::StaffingState;
+
+    /// The clock stands before every `Period` used by the tests so that all
+    /// of them are drafts.
+    fn mock_clock() -> MockClock
+    {
+        MockClock::new(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    }
 
     #[test]
     fn test_schedule_graph_new()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
         let index_worker = schedule_graph.add_node(Node::Technician(1234));
@@ -606,18 +1477,18 @@ mod tests
     #[test]
     fn test_add_work_order()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
-        let _skill_node_id = schedule_graph.add_node(Node::Skill(Skill::MtnMech));
+        let _skill_node_id = schedule_graph.add_node(Node::Skill(Skill::new("MtnMech")));
 
         let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
         let work_order = WorkOrder::new(
             1122334455,
             basic_start_date,
             vec![
-                Activity::new(10, 1, Skill::MtnMech),
-                Activity::new(20, 1, Skill::MtnMech),
-                Activity::new(30, 1, Skill::MtnMech),
+                Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(4), TimeDelta::hours(4)),
+                Activity::new(20, 1, Skill::new("MtnMech"), TimeDelta::hours(4), TimeDelta::hours(4)),
+                Activity::new(30, 1, Skill::new("MtnMech"), TimeDelta::hours(4), TimeDelta::hours(4)),
             ],
         )
         .unwrap();
@@ -635,21 +1506,27 @@ mod tests
             schedule_graph.nodes[work_order_node_id + 1],
             Node::Activity(crate::schedule_graph::ActivityNode {
                 activity_number: 10,
-                number_of_people: 1
+                number_of_people: 1,
+                work: TimeDelta::hours(4),
+                duration: TimeDelta::hours(4),
             })
         );
         assert_eq!(
             schedule_graph.nodes[work_order_node_id + 2],
             Node::Activity(crate::schedule_graph::ActivityNode {
                 activity_number: 20,
-                number_of_people: 1
+                number_of_people: 1,
+                work: TimeDelta::hours(4),
+                duration: TimeDelta::hours(4),
             })
         );
         assert_eq!(
             schedule_graph.nodes[work_order_node_id + 3],
             Node::Activity(crate::schedule_graph::ActivityNode {
                 activity_number: 30,
-                number_of_people: 1
+                number_of_people: 1,
+                work: TimeDelta::hours(4),
+                duration: TimeDelta::hours(4),
             })
         );
 
@@ -698,20 +1575,21 @@ mod tests
             let edge_type = &hyper_edge.edge_type;
             let nodes = &hyper_edge.nodes;
             match edge_type {
-                EdgeType::Assign(_) => todo!(),
-                EdgeType::Available => todo!(),
+                EdgeType::Assign(..) => todo!(),
+                EdgeType::Available(_) => todo!(),
                 EdgeType::BasicStart => {
                     assert_eq!(basic_start_day_node_id, nodes[1]);
                     assert_eq!(work_order_node_id, nodes[0]);
                 }
+                EdgeType::LatestFinish => todo!(),
                 EdgeType::Contains => {
                     assert_eq!(work_order_node_id, nodes[0]);
                 }
-                EdgeType::Requires => todo!(),
+                EdgeType::Requires(..) => todo!(),
                 EdgeType::StartStart => todo!(),
                 EdgeType::FinishStart => todo!(),
                 EdgeType::Exclude => todo!(),
-                EdgeType::HasSkill => todo!(),
+                EdgeType::HasSkill(_) => todo!(),
             }
         }
 
@@ -722,7 +1600,7 @@ mod tests
     #[test]
     fn test_add_technician()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(8, 0, 0).unwrap();
         let end = NaiveDate::from_ymd_opt(2025, 1, 7).unwrap().and_hms_opt(17, 0, 0).unwrap();
@@ -730,10 +1608,10 @@ mod tests
         let technician = Technician::builder(1)
             .add_availability(start, end)
             .unwrap()
-            .add_skill(Skill::MtnMech)
+            .add_skill(Skill::new("MtnMech"))
             .build();
 
-        schedule_graph.add_node(Node::Skill(Skill::MtnMech));
+        schedule_graph.add_node(Node::Skill(Skill::new("MtnMech")));
 
         schedule_graph.add_period(Period::from_start_date(start.date())).unwrap();
 
@@ -741,7 +1619,7 @@ mod tests
 
         schedule_graph.add_technician(technician, availability).unwrap();
 
-        assert_eq!(schedule_graph.nodes[0], Node::Skill(Skill::MtnMech));
+        assert_eq!(schedule_graph.nodes[0], Node::Skill(Skill::new("MtnMech")));
 
         for index in 1..=14 {
             let date = start.date();
@@ -753,9 +1631,16 @@ mod tests
         // TODO [ ] - This should be made into a method for retriving the correct
         // indices
         assert_eq!(schedule_graph.hyperedges[0].nodes, vec![16, 0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(
+            schedule_graph.hyperedges[1],
+            HyperEdge {
+                edge_type: EdgeType::HasSkill(Qualification::default()),
+                nodes: vec![16, 0],
+            }
+        );
 
-        assert_eq!(schedule_graph.incidence_list[16], vec![0]);
-        assert_eq!(schedule_graph.incidence_list[0], vec![0]);
+        assert_eq!(schedule_graph.incidence_list[16], vec![0, 1]);
+        assert_eq!(schedule_graph.incidence_list[0], vec![0, 1]);
         assert_eq!(schedule_graph.incidence_list[1], vec![0]);
         assert_eq!(schedule_graph.incidence_list[2], vec![0]);
         assert_eq!(schedule_graph.incidence_list[3], vec![0]);
@@ -772,7 +1657,7 @@ mod tests
     #[test]
     fn test_neighbors()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
         let technician_node_1 = Node::Technician(1234);
@@ -814,22 +1699,55 @@ mod tests
     #[test]
     fn test_skill_assign()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let _worker_node = schedule_graph.add_node(Node::Technician(1234));
-        let _skill_node = schedule_graph.add_node(Node::Skill(Skill::MtnMech));
+        let _skill_node = schedule_graph.add_node(Node::Skill(Skill::new("MtnMech")));
 
-        assert!(schedule_graph.add_assign_skill_to_worker(1234, Skill::MtnMech).is_ok());
+        assert!(
+            schedule_graph
+                .add_assign_skill_to_worker(1234, Skill::new("MtnMech"), Qualification::default())
+                .is_ok()
+        );
         assert_eq!(
-            schedule_graph.add_assign_skill_to_worker(1234, Skill::MtnElec),
+            schedule_graph.add_assign_skill_to_worker(1234, Skill::new("MtnElec"), Qualification::default()),
             Err(ScheduleGraphErrors::SkillMissing)
         );
     }
 
+    #[test]
+    fn test_add_skill_catalog()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        assert_eq!(
+            schedule_graph.add_skill(Skill::new("MtnMech")),
+            Err(ScheduleGraphErrors::SkillNotInCatalog)
+        );
+
+        let existing_skill_nodes = schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+
+        let skill_catalog = ["MtnMech", "MtnScaf", "MtnRope"].into_iter().map(Skill::new).collect::<SkillCatalog>();
+        let skill_nodes = schedule_graph.add_skill_catalog(&skill_catalog);
+
+        assert_eq!(skill_nodes.len(), 3);
+        assert!(skill_nodes.contains(&existing_skill_nodes[0]));
+        assert_eq!(schedule_graph.skill_indices.len(), 3);
+        assert!(schedule_graph.skill_indices.contains_key(&Skill::new("MtnScaf")));
+        assert_eq!(
+            schedule_graph.add_skill(Skill::new("MtnScaf")),
+            Ok(schedule_graph.skill_indices[&Skill::new("MtnScaf")])
+        );
+        assert_eq!(
+            schedule_graph.add_skill(Skill::new("MtnElec")),
+            Err(ScheduleGraphErrors::SkillNotInCatalog)
+        );
+    }
+
     #[test]
     fn test_add_period()
     {
-        let mut schedule_state = ScheduleGraph::new();
+        let mut schedule_state = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
         let period_2 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
@@ -865,7 +1783,7 @@ mod tests
     #[test]
     fn test_multi_directional_hypergraph()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let node_0 = Node::WorkOrder(1111990000);
         let node_1 = Node::WorkOrder(1111990001);
@@ -885,9 +1803,9 @@ mod tests
         let node_index_6 = schedule_graph.add_node(node_6);
         let node_index_7 = schedule_graph.add_node(node_7);
 
-        let edge_index_0 = schedule_graph.add_edge(EdgeType::Assign(None), vec![0, 2, 4, 6]);
-        let edge_index_1 = schedule_graph.add_edge(EdgeType::Assign(None), vec![1, 3, 5, 7]);
-        let edge_index_2 = schedule_graph.add_edge(EdgeType::Assign(None), vec![0, 3, 6]);
+        let edge_index_0 = schedule_graph.add_edge(EdgeType::Assign(None, LockState::Unlocked), vec![0, 2, 4, 6]);
+        let edge_index_1 = schedule_graph.add_edge(EdgeType::Assign(None, LockState::Unlocked), vec![1, 3, 5, 7]);
+        let edge_index_2 = schedule_graph.add_edge(EdgeType::Assign(None, LockState::Unlocked), vec![0, 3, 6]);
 
         assert_eq!(schedule_graph.incidence_list[node_index_0], vec![edge_index_0, edge_index_2]);
         assert_eq!(schedule_graph.incidence_list[node_index_1], vec![edge_index_1]);
@@ -902,7 +1820,7 @@ mod tests
     #[test]
     fn test_add_exclusion()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
         let work_order = WorkOrder::new(1111990000, basic_start_date, vec![]).unwrap();
@@ -929,10 +1847,355 @@ mod tests
         assert!(schedule_graph.incidence_list[period_node_index].contains(&exclusion_edge_index));
     }
 
+    #[test]
+    fn test_remove_assignments_work_order()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+        let period_0 = Period::from_start_date(basic_start_date);
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap());
+        schedule_graph.add_period(period_0).unwrap();
+        schedule_graph.add_period(period_1).unwrap();
+
+        let work_order_0 = WorkOrder::new(1111990000, basic_start_date, vec![]).unwrap();
+        let work_order_1 = WorkOrder::new(1111990001, basic_start_date, vec![]).unwrap();
+        let work_order_node_index = schedule_graph.add_work_order(&work_order_0).unwrap();
+        schedule_graph.add_work_order(&work_order_1).unwrap();
+
+        schedule_graph.add_assignment_work_order_period(&1111990000, &period_0).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1111990001, &period_1).unwrap();
+        schedule_graph.add_exclusion(&1111990000, &period_1).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1111990000, &period_1).unwrap();
+        let availability = Availability::new(
+            basic_start_date.and_hms_opt(7, 0, 0).unwrap(),
+            basic_start_date.and_hms_opt(17, 0, 0).unwrap(),
+        );
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+        schedule_graph.add_assignment_work_order(1001, 1111990001, period_1).unwrap();
+        let hyperedge_count = schedule_graph.hyperedge_count();
+
+        let mut removed_periods = schedule_graph.remove_assignments_work_order(&1111990000).unwrap();
+        removed_periods.sort();
+        assert_eq!(removed_periods, vec![period_0, period_1]);
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count - 2);
+
+        // The incidence list still matches the hyperedges after they were moved
+        for (edge_index, hyperedge) in schedule_graph.hyperedges.iter().enumerate() {
+            for node_index in &hyperedge.nodes {
+                assert!(schedule_graph.incidence_list[*node_index].contains(&edge_index));
+            }
+        }
+        let incidence_count = schedule_graph.incidence_list.iter().map(Vec::len).sum::<usize>();
+        assert_eq!(
+            incidence_count,
+            schedule_graph.hyperedges.iter().map(|hyperedge| hyperedge.nodes.len()).sum::<usize>()
+        );
+
+        assert!(
+            schedule_graph.incidence_list[work_order_node_index]
+                .iter()
+                .all(|&edge_index| !matches!(schedule_graph.hyperedges[edge_index].edge_type, EdgeType::Assign(..)))
+        );
+        assert_eq!(schedule_graph.remove_assignments_work_order(&1111990001).unwrap(), vec![period_1]);
+        // The `Technician` stays assigned to the `WorkOrder`, which is not a
+        // part of the strategic plan
+        assert_eq!(schedule_graph.work_order_assigned_period(&1111990001), Ok(None));
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count - 3);
+        assert_eq!(schedule_graph.remove_assignments_work_order(&1111990001).unwrap(), vec![]);
+        assert_eq!(
+            schedule_graph.remove_assignments_work_order(&1111990002),
+            Err(ScheduleGraphErrors::WorkOrderMissing)
+        );
+    }
+
+    #[test]
+    fn test_move_work_order()
+    {
+        let system_clock = mock_clock();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 15).unwrap());
+        schedule_graph.add_period(period_0).unwrap();
+        schedule_graph.add_period(period_1).unwrap();
+
+        let work_order = WorkOrder::new(1111990000, period_0.start_date(), vec![]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let availability = Availability::new(
+            period_0.start_date().and_hms_opt(7, 0, 0).unwrap(),
+            period_0.start_date().and_hms_opt(17, 0, 0).unwrap(),
+        );
+        for technician_id in [1001, 1002] {
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .build();
+            schedule_graph.add_technician(technician, availability.clone()).unwrap();
+            schedule_graph.add_assignment_work_order(technician_id, 1111990000, period_0).unwrap();
+        }
+        schedule_graph.add_assignment_work_order_period(&1111990000, &period_0).unwrap();
+        let hyperedge_count = schedule_graph.hyperedge_count();
+
+        assert_eq!(schedule_graph.move_work_order(&1111990000, Some(&period_1)), Ok(vec![period_0]));
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count);
+        assert_eq!(schedule_graph.work_order_assigned_period(&1111990000), Ok(Some(period_1)));
+        assert!(schedule_graph.validate_hierarchy().is_consistent());
+
+        // A failed check leaves the `ScheduleGraph` untouched
+        assert_eq!(
+            schedule_graph.move_work_order(&1111990000, Some(&Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 29).unwrap()))),
+            Err(ScheduleGraphErrors::PeriodMissing)
+        );
+        system_clock.set(period_1.end_date().and_hms_opt(23, 0, 0).unwrap() + TimeDelta::days(1));
+        assert_eq!(
+            schedule_graph.check_move_work_order(&1111990000, None),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+        assert_eq!(
+            schedule_graph.move_work_order(&1111990000, None),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count);
+        assert_eq!(schedule_graph.work_order_assigned_period(&1111990000), Ok(Some(period_1)));
+    }
+
+    #[test]
+    fn test_assignment_activity_days()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            1111990000,
+            period.start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(16), TimeDelta::hours(16))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let days = vec![
+            NaiveDate::from_ymd_opt(2025, 1, 15).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 1, 14).unwrap(),
+        ];
+        schedule_graph.add_assignment_activity_days(&1111990000, 10, days.clone()).unwrap();
+        assert_eq!(schedule_graph.activity_planned_days(&1111990000, 10).unwrap(), vec![days[1], days[0]]);
+        // Planning the days of an `Activity` does not assign the `WorkOrder`
+        assert_eq!(schedule_graph.work_order_assigned_period(&1111990000), Ok(None));
+
+        assert_eq!(
+            schedule_graph.add_assignment_activity_days(&1111990000, 20, days.clone()),
+            Err(ScheduleGraphErrors::ActivityMissing)
+        );
+        assert_eq!(
+            schedule_graph.add_assignment_activity_days(&1111990000, 10, vec![NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()]),
+            Err(ScheduleGraphErrors::DayMissing)
+        );
+
+        schedule_graph.freeze_period(&period).unwrap();
+        assert!(schedule_graph.is_activity_days_locked(&1111990000, 10).unwrap());
+        assert_eq!(
+            schedule_graph.remove_assignment_activity_days(&1111990000, 10),
+            Err(ScheduleGraphErrors::AssignmentLocked)
+        );
+    }
+
+    #[test]
+    fn test_remove_assignment_activity_days()
+    {
+        let system_clock = mock_clock();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            1111990000,
+            period.start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        let hyperedge_count = schedule_graph.hyperedge_count();
+
+        let day = NaiveDate::from_ymd_opt(2025, 1, 14).unwrap();
+        schedule_graph.add_assignment_activity_days(&1111990000, 10, vec![day]).unwrap();
+        assert!(!schedule_graph.is_activity_days_locked(&1111990000, 10).unwrap());
+
+        assert_eq!(schedule_graph.remove_assignment_activity_days(&1111990000, 10), Ok(vec![day]));
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count);
+        assert!(schedule_graph.activity_planned_days(&1111990000, 10).unwrap().is_empty());
+
+        // The day plans of a `Previous` `Period` are kept
+        schedule_graph.add_assignment_activity_days(&1111990000, 10, vec![day]).unwrap();
+        system_clock.set(period.end_date().and_hms_opt(23, 0, 0).unwrap() + TimeDelta::days(1));
+        assert_eq!(
+            schedule_graph.remove_assignment_activity_days(&1111990000, 10),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+        assert_eq!(schedule_graph.activity_planned_days(&1111990000, 10), Ok(vec![day]));
+
+        // An archived `Period` can not be planned either
+        schedule_graph.roll_horizon().unwrap();
+        assert_eq!(
+            schedule_graph.add_assignment_activity_days(&1111990000, 10, vec![day]),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+    }
+
+    #[test]
+    fn test_retime_assignment_activity()
+    {
+        let system_clock = mock_clock();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            1111990000,
+            period.start_date(),
+            vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(32), TimeDelta::hours(16))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let days = period.start_date().iter_days().take(2).collect::<Vec<_>>();
+        for technician_id in [1001, 1002] {
+            let availability = Availability::new(days[0].and_hms_opt(7, 0, 0).unwrap(), days[1].and_hms_opt(17, 0, 0).unwrap());
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .add_skill(Skill::new("MtnMech"))
+                .build();
+            schedule_graph.add_technician(technician, availability).unwrap();
+        }
+
+        let time = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001, 1002], 1111990000, 10, days.clone(), (time(8), time(15)))
+            .unwrap();
+        let hyperedge_count = schedule_graph.hyperedge_count();
+
+        let edge_index = schedule_graph
+            .retime_assignment_activity(1001, &1111990000, 10, days[0], (time(9), time(16)))
+            .unwrap();
+        assert_eq!(
+            schedule_graph.hyperedges[edge_index].edge_type,
+            EdgeType::Assign(Some((time(9), time(16))), LockState::Unlocked)
+        );
+        // The assignment is split for the other technician and the other day
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count + 2);
+        assert_eq!(
+            schedule_graph.technician_assignment_time(1001, &1111990000, 10, days[0]),
+            Ok(Some((time(9), time(16))))
+        );
+        assert_eq!(
+            schedule_graph.technician_assignment_time(1001, &1111990000, 10, days[1]),
+            Ok(Some((time(8), time(15))))
+        );
+        assert_eq!(
+            schedule_graph.technician_assignment_time(1002, &1111990000, 10, days[0]),
+            Ok(Some((time(8), time(15))))
+        );
+        assert_eq!(
+            schedule_graph.technician_assignment_time(1002, &1111990000, 10, days[1]),
+            Ok(Some((time(8), time(15))))
+        );
+        // The crew of each day is still complete after the split
+        assert_eq!(schedule_graph.activity_staffing(&1111990000, 10), Ok(StaffingState::FullyStaffed));
+
+        assert_eq!(
+            schedule_graph.retime_assignment_activity(1002, &1111990000, 10, days[0], (time(5), time(17))),
+            Err(ScheduleGraphErrors::ActivityExceedWork)
+        );
+        assert_eq!(
+            schedule_graph.retime_assignment_activity(1001, &1111990000, 10, days[0] - TimeDelta::days(1), (time(9), time(16))),
+            Err(ScheduleGraphErrors::DayMissing)
+        );
+        schedule_graph.freeze_period(&period).unwrap();
+        assert_eq!(
+            schedule_graph.retime_assignment_activity(1002, &1111990000, 10, days[1], (time(9), time(16))),
+            Err(ScheduleGraphErrors::AssignmentLocked)
+        );
+
+        // The archived `Period` is checked before the assignment is looked up
+        system_clock.set(period.end_date().and_hms_opt(23, 0, 0).unwrap() + TimeDelta::days(1));
+        schedule_graph.roll_horizon().unwrap();
+        assert_eq!(
+            schedule_graph.retime_assignment_activity(1001, &1111990000, 10, days[0], (time(9), time(16))),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+    }
+
+    #[test]
+    fn test_period_state_lifecycle()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let periods = [13, 27]
+            .into_iter()
+            .map(|day| Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap()))
+            .chain([Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())])
+            .collect::<Vec<_>>();
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+        for work_order_number in [1111990000, 1111990001] {
+            let work_order = WorkOrder::new(work_order_number, periods[0].start_date(), vec![]).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+
+        assert_eq!(schedule_graph.period_state(&periods[0]), Ok(PeriodState::Draft));
+        assert_eq!(schedule_graph.period_state(&periods[1]), Ok(PeriodState::Draft2));
+        schedule_graph.add_assignment_work_order_period(&1111990000, &periods[0]).unwrap();
+
+        // The first `Period` starts
+        system_clock.advance(TimeDelta::days(13));
+        assert_eq!(schedule_graph.period_state(&periods[0]), Ok(PeriodState::Frozen));
+        assert_eq!(schedule_graph.period_state(&periods[1]), Ok(PeriodState::Draft));
+        assert_eq!(
+            schedule_graph.add_assignment_work_order_period(&1111990001, &periods[0]),
+            Err(ScheduleGraphErrors::PeriodFrozen)
+        );
+        assert_eq!(
+            schedule_graph.remove_assignments_work_order(&1111990000),
+            Err(ScheduleGraphErrors::PeriodFrozen)
+        );
+        assert_eq!(
+            schedule_graph.add_exclusion(&1111990001, &periods[0]),
+            Err(ScheduleGraphErrors::PeriodFrozen)
+        );
+        schedule_graph.add_exclusion(&1111990001, &periods[1]).unwrap();
+        schedule_graph.freeze_period(&periods[0]).unwrap();
+
+        // The first `Period` is over
+        system_clock.set(periods[1].start_date().and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(schedule_graph.period_state(&periods[0]), Ok(PeriodState::Previous));
+        assert_eq!(schedule_graph.period_state(&periods[1]), Ok(PeriodState::Frozen));
+        assert_eq!(schedule_graph.period_state(&periods[2]), Ok(PeriodState::Draft));
+        assert_eq!(schedule_graph.freeze_period(&periods[0]), Err(ScheduleGraphErrors::PeriodPrevious));
+        assert_eq!(
+            schedule_graph.add_assignment_work_order_period(&1111990001, &periods[0]),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+        schedule_graph.add_assignment_work_order_period(&1111990001, &periods[2]).unwrap();
+    }
+
     #[test]
     fn test_add_assignment_activity()
     {
-        let mut schedule_graph = ScheduleGraph::new();
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
 
         // Create test dates
         let basic_start_date_0 = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
@@ -943,8 +2206,8 @@ mod tests
         let availability_end_1 = basic_start_date_1.and_hms_opt(17, 0, 0).unwrap();
 
         // Add required skills first
-        let _skill_node_mech = schedule_graph.add_node(Node::Skill(Skill::MtnMech));
-        let _skill_node_elec = schedule_graph.add_node(Node::Skill(Skill::MtnElec));
+        let _skill_node_mech = schedule_graph.add_node(Node::Skill(Skill::new("MtnMech")));
+        let _skill_node_elec = schedule_graph.add_node(Node::Skill(Skill::new("MtnElec")));
 
         // Add period (creates day nodes)
         let period = Period::from_start_date(basic_start_date_0);
@@ -957,8 +2220,8 @@ mod tests
             1122334455,
             basic_start_date_0,
             vec![
-                Activity::new(10, 2, Skill::MtnMech), // Activity 10, 2 hours, MtnMech skill
-                Activity::new(20, 3, Skill::MtnElec), // Activity 20, 3 hours, MtnElec skill
+                Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(6), TimeDelta::hours(3)), // Activity 10, 2 people, MtnMech skill
+                Activity::new(20, 3, Skill::new("MtnElec"), TimeDelta::hours(9), TimeDelta::hours(3)), // Activity 20, 3 people, MtnElec skill
             ],
         )
         .unwrap();
@@ -970,19 +2233,20 @@ mod tests
         let technician_1 = Technician::builder(1001)
             .add_availability(availability_start_0, availability_end_0)
             .unwrap()
-            .add_skill(Skill::MtnMech)
+            .add_skill(Skill::new("MtnMech"))
             .build();
 
         let technician_2 = Technician::builder(1002)
             .add_availability(availability_start_1, availability_end_1)
             .unwrap()
-            .add_skill(Skill::MtnElec)
+            .add_skill(Skill::new("MtnElec"))
             .build();
 
         let technician_3 = Technician::builder(1003)
             .add_availability(availability_start_0, availability_end_0)
             .unwrap()
-            .add_skill(Skill::MtnElec)
+            .add_skill(Skill::new("MtnMech"))
+            .add_skill(Skill::new("MtnElec"))
             .build();
 
         // Add technicians to graph
@@ -1020,7 +2284,7 @@ mod tests
         let hyperedge = &schedule_graph.hyperedges[assignment_edge];
 
         // Should be an assignment edge
-        assert!(matches!(hyperedge.edge_type, EdgeType::Assign(Some(_))));
+        assert!(matches!(hyperedge.edge_type, EdgeType::Assign(Some(_), _)));
 
         // Should contain activity + 2 technicians + 1 day = 4 nodes
         assert_eq!(hyperedge.nodes.len(), 4); // activity + 2 technicians + 1 day
@@ -1038,5 +2302,171 @@ mod tests
         // Verify the activity node is in the assignment
         let day_node_id = *schedule_graph.day_indices.get(&basic_start_date_0).unwrap();
         assert!(hyperedge.nodes.contains(&day_node_id));
+
+        // 4 of the 6 hours of work are booked, so a further 2 x 2 hours exceeds the
+        // planned work.
+        let assignment_edge_error = schedule_graph.add_assignment_activity(
+            vec![1001, 1003],
+            1122334455,
+            10,
+            vec![basic_start_date_0],
+            (NaiveTime::from_hms_opt(13, 0, 0).unwrap(), NaiveTime::from_hms_opt(15, 0, 0).unwrap()),
+        );
+        assert_eq!(assignment_edge_error, Err(ScheduleGraphErrors::ActivityExceedWork));
+
+        assert!(
+            schedule_graph
+                .add_assignment_activity(
+                    vec![1001],
+                    1122334455,
+                    10,
+                    vec![basic_start_date_0],
+                    (NaiveTime::from_hms_opt(13, 0, 0).unwrap(), NaiveTime::from_hms_opt(15, 0, 0).unwrap()),
+                )
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_add_assignment_activity_qualifications()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = (basic_start_date + Duration::days(4)).and_hms_opt(17, 0, 0).unwrap();
+
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech"), Skill::new("MtnElec")]));
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            basic_start_date,
+            vec![
+                Activity::new(10, 1, Skill::new("MtnElec"), TimeDelta::hours(16), TimeDelta::hours(16))
+                    .with_minimum_proficiency(Proficiency::Journeyman),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let certificate_expiry = basic_start_date + Duration::days(1);
+        let technicians = [
+            (2001, Skill::new("MtnMech"), Qualification::new(Proficiency::Certified)),
+            (2002, Skill::new("MtnElec"), Qualification::new(Proficiency::Apprentice)),
+            (
+                2003,
+                Skill::new("MtnElec"),
+                Qualification::new(Proficiency::Certified)
+                    .valid_between(basic_start_date, certificate_expiry)
+                    .unwrap(),
+            ),
+        ];
+        for (technician_id, skill, qualification) in technicians {
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability_start, availability_end)
+                .unwrap()
+                .add_qualified_skill(skill, qualification)
+                .build();
+            schedule_graph
+                .add_technician(technician, Availability::new(availability_start, availability_end))
+                .unwrap();
+        }
+
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+        let mut assign =
+            |technician_id, day| schedule_graph.add_assignment_activity(vec![technician_id], 1122334455, 10, vec![day], start_and_finish_time);
+
+        assert_eq!(assign(2001, basic_start_date), Err(ScheduleGraphErrors::WorkerMissingSkill));
+        assert_eq!(assign(2002, basic_start_date), Err(ScheduleGraphErrors::WorkerInsufficientProficiency));
+        assert!(assign(2003, basic_start_date).is_ok());
+        assert!(assign(2003, certificate_expiry).is_ok());
+        assert_eq!(
+            assign(2003, certificate_expiry + Duration::days(1)),
+            Err(ScheduleGraphErrors::WorkerQualificationExpired)
+        );
+    }
+
+    #[test]
+    fn test_add_assignment_activity_skill_requirements()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let basic_start_date = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap();
+        let availability_start = basic_start_date.and_hms_opt(8, 0, 0).unwrap();
+        let availability_end = (basic_start_date + TimeDelta::days(3)).and_hms_opt(17, 0, 0).unwrap();
+
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech"), Skill::new("MtnElec")]));
+        schedule_graph.add_period(Period::from_start_date(basic_start_date)).unwrap();
+
+        // Two mechanics and one electrician
+        let work_order = WorkOrder::new(
+            1122334455,
+            basic_start_date,
+            vec![Activity::with_requirements(
+                10,
+                vec![
+                    SkillRequirement::new(Skill::new("MtnMech"), 2),
+                    SkillRequirement::new(Skill::new("MtnElec"), 1),
+                ],
+                TimeDelta::hours(60),
+                TimeDelta::hours(20),
+            )],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let technicians = [
+            (3001, vec!["MtnMech"]),
+            (3002, vec!["MtnMech"]),
+            (3003, vec!["MtnMech"]),
+            (3004, vec!["MtnElec"]),
+            (3005, vec!["MtnMech", "MtnElec"]),
+        ];
+        for (technician_id, skills) in technicians {
+            let mut builder = Technician::builder(technician_id)
+                .add_availability(availability_start, availability_end)
+                .unwrap();
+            for skill in skills {
+                builder = builder.add_skill(Skill::new(skill));
+            }
+            schedule_graph
+                .add_technician(builder.build(), Availability::new(availability_start, availability_end))
+                .unwrap();
+        }
+
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(10, 0, 0).unwrap());
+        let mut assign = |technician_ids: Vec<usize>, day: i64| {
+            schedule_graph.add_assignment_activity(
+                technician_ids,
+                1122334455,
+                10,
+                vec![basic_start_date + TimeDelta::days(day)],
+                start_and_finish_time,
+            )
+        };
+
+        assert!(assign(vec![3001, 3002, 3004], 0).is_ok());
+        // The multi skilled technician has to take the place of the electrician
+        assert!(assign(vec![3005, 3001, 3002], 1).is_ok());
+        assert!(assign(vec![3004], 2).is_ok());
+        assert_eq!(
+            assign(vec![3001, 3002, 3003], 3),
+            Err(ScheduleGraphErrors::ActivityExceedSkillRequirement)
+        );
+        assert_eq!(
+            assign(vec![3004, 3005, 3001, 3002], 3),
+            Err(ScheduleGraphErrors::ActivityExceedNumberOfPeople)
+        );
+
+        // The crews of the earlier assignments on the same day take their
+        // places
+        assert_eq!(assign(vec![3003], 0), Err(ScheduleGraphErrors::ActivityExceedNumberOfPeople));
+        assert!(assign(vec![3001], 3).is_ok());
+        assert!(assign(vec![3002], 3).is_ok());
+        assert_eq!(assign(vec![3003], 3), Err(ScheduleGraphErrors::ActivityExceedSkillRequirement));
+        assert!(assign(vec![3005], 3).is_ok());
+        // The same `Technician` only takes one place
+        assert!(assign(vec![3001], 3).is_ok());
     }
 }
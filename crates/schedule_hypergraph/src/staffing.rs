@@ -0,0 +1,309 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::NumberOfPeople;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// How well the crew of an `Activity` is covered by `EdgeType::Assign`
+/// hyperedges.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StaffingState
+{
+    Unassigned,
+    PartiallyStaffed
+    {
+        assigned: NumberOfPeople,
+        required: NumberOfPeople,
+    },
+    FullyStaffed,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnderstaffedActivity
+{
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub staffing_state: StaffingState,
+}
+
+/// Public API to report the staffing of the `Activity`s in the graph.
+impl ScheduleGraph
+{
+    /// The crew of an `Activity` on a day is every `Technician` that an
+    /// `EdgeType::Assign` hyperedge assigns to it on that day. The `Activity`
+    /// is only fully staffed when the crew of every one of its days is as
+    /// large as the `number_of_people` of the `Activity`.
+    pub fn activity_staffing(
+        &self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<StaffingState, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        Ok(self.staffing_state(activity_node_index, |_| true))
+    }
+
+    /// Every `Activity` that is planned for the `Period` without its full crew.
+    /// An `Activity` is planned for the `Period` when its `WorkOrder` is
+    /// assigned to the `Period` or when it has assignments on days inside of
+    /// the `Period`.
+    pub fn understaffed_activities(&self, period: &Period) -> Result<Vec<UnderstaffedActivity>, ScheduleGraphErrors>
+    {
+        let period_node_index = *self.period_node_index(period)?;
+        let is_in_period = |edge_index: EdgeIndex| {
+            self.hyperedges()[edge_index]
+                .nodes()
+                .iter()
+                .any(|&node_index| match self.nodes()[node_index] {
+                    Node::Day(day) => period.contains(day),
+                    _ => false,
+                })
+        };
+
+        let mut planned_activities = BTreeMap::new();
+        for &edge_index in &self.incidence_list()[period_node_index] {
+            let hyperedge = &self.hyperedges()[edge_index];
+            if !matches!(hyperedge.edge_type(), EdgeType::Assign(None, _)) {
+                continue;
+            }
+            for &node_index in hyperedge.nodes() {
+                if let Node::WorkOrder(work_order_number) = self.nodes()[node_index] {
+                    for activity_node_index in self.work_order_activities(&work_order_number)? {
+                        planned_activities.insert(activity_node_index, work_order_number);
+                    }
+                }
+            }
+        }
+
+        for (activity_node_index, node) in self.nodes().iter().enumerate() {
+            if !matches!(node, Node::Activity(_)) {
+                continue;
+            }
+            let assigned_in_period = self.incidence_list()[activity_node_index]
+                .iter()
+                .any(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(Some(_), _)) && is_in_period(edge_index));
+            if assigned_in_period {
+                planned_activities.insert(activity_node_index, self.activity_work_order(activity_node_index));
+            }
+        }
+
+        let mut understaffed_activities = planned_activities
+            .into_iter()
+            .filter_map(|(activity_node_index, work_order_number)| {
+                let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                    unreachable!()
+                };
+                match self.staffing_state(activity_node_index, |day| period.contains(day)) {
+                    StaffingState::FullyStaffed => None,
+                    staffing_state => Some(UnderstaffedActivity {
+                        work_order_number,
+                        activity_number: activity.activity_number(),
+                        staffing_state,
+                    }),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        understaffed_activities.sort_by_key(|understaffed_activity| (understaffed_activity.work_order_number, understaffed_activity.activity_number));
+        Ok(understaffed_activities)
+    }
+}
+
+/// Private helper methods for the staffing
+impl ScheduleGraph
+{
+    /// Only the days accepted by `include_day` are counted.
+    fn staffing_state(&self, activity_node_index: NodeIndex, include_day: impl Fn(NaiveDate) -> bool) -> StaffingState
+    {
+        let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+            unreachable!()
+        };
+
+        let smallest_crew = self
+            .activity_day_crews(activity_node_index)
+            .into_iter()
+            .filter(|(day, _)| include_day(*day))
+            .map(|(_, crew)| crew.len() as NumberOfPeople)
+            .min();
+
+        match smallest_crew {
+            None => StaffingState::Unassigned,
+            Some(assigned) if assigned < activity.number_of_people() => StaffingState::PartiallyStaffed {
+                assigned,
+                required: activity.number_of_people(),
+            },
+            Some(_) => StaffingState::FullyStaffed,
+        }
+    }
+
+    /// The `Technician`s that work on the `Activity` on each day, over all
+    /// of its assignments.
+    ///
+    /// Format: `vec![$activity, @technicians, @days]`
+    pub(crate) fn activity_day_crews(&self, activity_node_index: NodeIndex) -> BTreeMap<NaiveDate, BTreeSet<NodeIndex>>
+    {
+        let mut day_crews: BTreeMap<NaiveDate, BTreeSet<NodeIndex>> = BTreeMap::new();
+        for &edge_index in &self.incidence_list()[activity_node_index] {
+            let hyperedge = &self.hyperedges()[edge_index];
+            if !matches!(hyperedge.edge_type(), EdgeType::Assign(Some(_), _)) {
+                continue;
+            }
+            let technician_node_indices = hyperedge
+                .nodes()
+                .iter()
+                .copied()
+                .filter(|&node_index| matches!(self.nodes()[node_index], Node::Technician(_)))
+                .collect::<Vec<_>>();
+            for &node_index in hyperedge.nodes() {
+                if let Node::Day(day) = self.nodes()[node_index] {
+                    day_crews.entry(day).or_default().extend(&technician_node_indices);
+                }
+            }
+        }
+        day_crews
+    }
+
+    pub(crate) fn activity_node_index(
+        &self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<NodeIndex, ScheduleGraphErrors>
+    {
+        self.work_order_activities(work_order_number)?
+            .into_iter()
+            .find(|&node_index| matches!(&self.nodes()[node_index], Node::Activity(activity) if activity.activity_number() == activity_number))
+            .ok_or(ScheduleGraphErrors::ActivityMissing)
+    }
+
+    /// Format: `vec![$work_order, $activity]`
+    pub(crate) fn activity_work_order(&self, activity_node_index: NodeIndex) -> WorkOrderNumber
+    {
+        self.neighbors_by_edge_type(activity_node_index, |edge_type| matches!(edge_type, EdgeType::Contains))
+            .find_map(|node_index| match self.nodes()[node_index] {
+                Node::WorkOrder(work_order_number) => Some(work_order_number),
+                _ => None,
+            })
+            .expect("Every `Activity` is contained in a `WorkOrder`")
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::Duration;
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use super::StaffingState;
+    use super::UnderstaffedActivity;
+    use crate::schedule_graph::ScheduleGraph;
+
+    /// The clock stands before every `Period` used by the tests so that all
+    /// of them are drafts.
+    fn mock_clock() -> MockClock
+    {
+        MockClock::new(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_understaffed_activities()
+    {
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let next_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        let day = period.start_date() + Duration::days(2);
+
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period).unwrap();
+        schedule_graph.add_period(next_period).unwrap();
+
+        let work_orders = [
+            WorkOrder::new(
+                1122334455,
+                period.start_date(),
+                vec![
+                    Activity::new(10, 3, Skill::new("MtnMech"), TimeDelta::hours(24), TimeDelta::hours(8)),
+                    Activity::new(20, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8)),
+                ],
+            )
+            .unwrap(),
+            WorkOrder::new(
+                1122334456,
+                period.start_date(),
+                vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(4))],
+            )
+            .unwrap(),
+            WorkOrder::new(
+                1122334457,
+                next_period.start_date(),
+                vec![Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(4))],
+            )
+            .unwrap(),
+        ];
+        for work_order in &work_orders {
+            schedule_graph.add_work_order(work_order).unwrap();
+        }
+
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 10, vec![day], start_and_finish_time)
+            .unwrap();
+        schedule_graph
+            .add_assignment_activity(vec![1001], 1122334455, 20, vec![day], start_and_finish_time)
+            .unwrap();
+        schedule_graph.add_assignment_work_order(1001, 1122334456, period).unwrap();
+
+        assert_eq!(
+            schedule_graph.activity_staffing(&1122334455, 10),
+            Ok(StaffingState::PartiallyStaffed { assigned: 1, required: 3 })
+        );
+        assert_eq!(schedule_graph.activity_staffing(&1122334455, 20), Ok(StaffingState::FullyStaffed));
+        assert_eq!(schedule_graph.activity_staffing(&1122334457, 10), Ok(StaffingState::Unassigned));
+
+        assert_eq!(
+            schedule_graph.understaffed_activities(&period).unwrap(),
+            vec![
+                UnderstaffedActivity {
+                    work_order_number: 1122334455,
+                    activity_number: 10,
+                    staffing_state: StaffingState::PartiallyStaffed { assigned: 1, required: 3 },
+                },
+                UnderstaffedActivity {
+                    work_order_number: 1122334456,
+                    activity_number: 10,
+                    staffing_state: StaffingState::Unassigned,
+                },
+            ]
+        );
+        assert_eq!(schedule_graph.understaffed_activities(&next_period).unwrap(), vec![]);
+    }
+}
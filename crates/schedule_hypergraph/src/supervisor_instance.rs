@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::NumberOfPeople;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::schedule_graph::EdgeIndex;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::FinishTime;
+use crate::schedule_graph::LockState;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+use crate::schedule_graph::StartTime;
+use crate::schedule_graph::TechnicianId;
+
+/// The view of a supervisor on a `Period`. It holds every `Activity` that is
+/// placed on the `Day`s of the `Period` together with the `Technician`s of
+/// the supervisor that could perform it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SupervisorInstance
+{
+    pub supervisor_technicians: Vec<TechnicianId>,
+    pub supervisor_activities: BTreeMap<(WorkOrderNumber, ActivityNumber), SupervisorActivity>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupervisorActivity
+{
+    /// The `Day`s of the `Period` that the `Activity` is placed on.
+    pub days: Vec<NaiveDate>,
+    pub number_of_people: NumberOfPeople,
+    pub work_load: HashMap<Skill, Work>,
+    /// The `Technician`s of the supervisor that are available on every one of
+    /// the `days` and qualified for at least one `Skill` of the `Activity`.
+    pub eligible_technicians: Vec<TechnicianId>,
+    /// Every `Technician` that is already assigned to the `Activity`, also
+    /// the ones outside of the supervisor's crew.
+    pub delegated_technicians: BTreeSet<TechnicianId>,
+}
+
+impl SupervisorActivity
+{
+    pub fn is_fully_delegated(&self) -> bool
+    {
+        self.delegated_technicians.len() >= self.number_of_people as usize
+    }
+}
+
+/// Public API for the supervisors to delegate `Activity`s to `Technician`s.
+impl ScheduleGraph
+{
+    /// Derives the `SupervisorInstance` for the `technicians` of a supervisor
+    /// in the `Period`. An `Activity` is a part of the `Period` when it is
+    /// placed on or assigned to one of its `Day`s.
+    pub fn derive_supervisor_instance(&self, technicians: &[TechnicianId], period: &Period) -> Result<SupervisorInstance, ScheduleGraphErrors>
+    {
+        self.period_node_index(period)?;
+        let technician_node_indices = technicians
+            .iter()
+            .map(|technician_id| Ok((*technician_id, *self.technician_node_index(technician_id)?)))
+            .collect::<Result<Vec<_>, ScheduleGraphErrors>>()?;
+
+        let mut supervisor_activities = BTreeMap::new();
+        for (activity_node_index, node) in self.nodes().iter().enumerate() {
+            let Node::Activity(activity) = node else {
+                continue;
+            };
+            let days = self
+                .activity_assigned_days(activity_node_index)
+                .into_iter()
+                .filter(|day| period.contains(*day))
+                .collect::<Vec<_>>();
+            if days.is_empty() {
+                continue;
+            }
+
+            let eligible_technicians = technician_node_indices
+                .iter()
+                .filter(|(_, technician_node_index)| {
+                    self.is_technician_available(*technician_node_index, &days)
+                        && self.is_technician_qualified_for_activity(*technician_node_index, activity_node_index, &days)
+                })
+                .map(|(technician_id, _)| *technician_id)
+                .collect();
+
+            let supervisor_activity = SupervisorActivity {
+                number_of_people: activity.number_of_people(),
+                work_load: self.activity_work_load(activity_node_index),
+                eligible_technicians,
+                delegated_technicians: self.activity_delegated_technicians(activity_node_index),
+                days,
+            };
+            supervisor_activities.insert(
+                (self.activity_work_order(activity_node_index), activity.activity_number()),
+                supervisor_activity,
+            );
+        }
+
+        Ok(SupervisorInstance {
+            supervisor_technicians: technicians.to_vec(),
+            supervisor_activities,
+        })
+    }
+
+    /// Assigns the `technicians` to the `Activity` on every `Day` that it is
+    /// placed on. The `Activity` has to be placed by
+    /// `ScheduleGraph::add_assignment_activity_days` first.
+    ///
+    /// Format
+    /// `vec![$activity, @technicians, @days]`
+    pub fn delegate_activity(
+        &mut self,
+        technicians: Vec<TechnicianId>,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+        start_and_finish_time: (StartTime, FinishTime),
+    ) -> Result<EdgeIndex, ScheduleGraphErrors>
+    {
+        let days = self.activity_planned_days(work_order_number, activity_number)?;
+        if days.is_empty() {
+            return Err(ScheduleGraphErrors::ActivityUnplanned);
+        }
+
+        self.add_assignment_activity(technicians, *work_order_number, activity_number, days, start_and_finish_time)
+    }
+
+    /// Removes every assignment of `Technician`s to the `Activity` and
+    /// returns the `Technician`s. The `Day`s that the `Activity` is placed on
+    /// are left untouched.
+    ///
+    /// Nothing is removed when one of the assignments is locked or lies in a
+    /// `Previous` `Period`.
+    ///
+    /// Removing hyperedges invalidates previously returned `EdgeIndex`s.
+    pub fn remove_delegations(
+        &mut self,
+        work_order_number: &WorkOrderNumber,
+        activity_number: ActivityNumber,
+    ) -> Result<BTreeSet<TechnicianId>, ScheduleGraphErrors>
+    {
+        let activity_node_index = self.activity_node_index(work_order_number, activity_number)?;
+
+        let mut delegation_edge_indices = self.incidence_list()[activity_node_index]
+            .iter()
+            .copied()
+            .filter(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(Some(_), _)))
+            .collect::<Vec<_>>();
+        if delegation_edge_indices
+            .iter()
+            .any(|&edge_index| matches!(self.hyperedges()[edge_index].edge_type(), EdgeType::Assign(_, LockState::Locked)))
+        {
+            return Err(ScheduleGraphErrors::AssignmentLocked);
+        }
+        for &edge_index in &delegation_edge_indices {
+            for &node_index in self.hyperedges()[edge_index].nodes() {
+                if let Node::Day(day) = self.nodes()[node_index] {
+                    self.check_day_not_previous(&day)?;
+                }
+            }
+        }
+
+        let technicians = self.activity_delegated_technicians(activity_node_index);
+        // Removing the largest `EdgeIndex` first keeps the remaining ones valid.
+        delegation_edge_indices.sort_unstable_by(|a, b| b.cmp(a));
+        for edge_index in delegation_edge_indices {
+            self.remove_edge(edge_index);
+        }
+        Ok(technicians)
+    }
+}
+
+impl ScheduleGraph
+{
+    /// Format: `vec![$activity, @technicians, @days]`
+    fn activity_delegated_technicians(&self, activity_node_index: NodeIndex) -> BTreeSet<TechnicianId>
+    {
+        self.incidence_list()[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Assign(Some(_), _)))
+            .flat_map(|hyperedge| hyperedge.nodes().iter())
+            .filter_map(|&node_index| match self.nodes()[node_index] {
+                Node::Technician(technician_id) => Some(technician_id),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeSet;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_supervisor_delegation()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock.clone());
+
+        let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech"), Skill::new("MtnElec")]));
+        schedule_graph.add_period(period).unwrap();
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            period.start_date(),
+            vec![
+                Activity::new(10, 2, Skill::new("MtnMech"), TimeDelta::hours(32), TimeDelta::hours(16)),
+                Activity::new(20, 1, Skill::new("MtnElec"), TimeDelta::hours(8), TimeDelta::hours(8)),
+                Activity::new(30, 1, Skill::new("MtnElec"), TimeDelta::hours(8), TimeDelta::hours(8)),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let days = period.start_date().iter_days().take(3).collect::<Vec<_>>();
+        for (technician_id, skill, number_of_days) in [(1001, "MtnMech", 3), (1002, "MtnMech", 1), (1003, "MtnElec", 3)] {
+            let start = period.start_date().and_hms_opt(7, 0, 0).unwrap();
+            let availability = Availability::new(start, start + TimeDelta::days(number_of_days) - TimeDelta::hours(12));
+            let technician = Technician::builder(technician_id)
+                .add_availability(availability.start(), availability.end())
+                .unwrap()
+                .add_skill(Skill::new(skill))
+                .build();
+            schedule_graph.add_technician(technician, availability).unwrap();
+        }
+        schedule_graph.add_assignment_activity_days(&1122334455, 10, days[..2].to_vec()).unwrap();
+        schedule_graph.add_assignment_activity_days(&1122334455, 20, days[2..].to_vec()).unwrap();
+
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap());
+        schedule_graph
+            .delegate_activity(vec![1003], &1122334455, 20, start_and_finish_time)
+            .unwrap();
+
+        let supervisor_instance = schedule_graph.derive_supervisor_instance(&[1001, 1002, 1003], &period).unwrap();
+
+        // The unplaced `Activity` is not a part of the supervisor's view
+        assert_eq!(
+            supervisor_instance.supervisor_activities.keys().collect::<Vec<_>>(),
+            vec![&(1122334455, 10), &(1122334455, 20)]
+        );
+
+        // 1002 is only available on the first day
+        let mechanic_activity = &supervisor_instance.supervisor_activities[&(1122334455, 10)];
+        assert_eq!(mechanic_activity.days, days[..2].to_vec());
+        assert_eq!(mechanic_activity.eligible_technicians, vec![1001]);
+        assert!(mechanic_activity.delegated_technicians.is_empty());
+
+        let electrician_activity = &supervisor_instance.supervisor_activities[&(1122334455, 20)];
+        assert_eq!(electrician_activity.eligible_technicians, vec![1003]);
+        assert_eq!(electrician_activity.delegated_technicians, BTreeSet::from([1003]));
+        assert!(electrician_activity.is_fully_delegated());
+
+        assert_eq!(
+            schedule_graph.delegate_activity(vec![1003], &1122334455, 30, start_and_finish_time),
+            Err(ScheduleGraphErrors::ActivityUnplanned)
+        );
+        assert_eq!(
+            schedule_graph.delegate_activity(vec![1002], &1122334455, 10, start_and_finish_time),
+            Err(ScheduleGraphErrors::WorkerUnavailable)
+        );
+        assert_eq!(
+            schedule_graph.derive_supervisor_instance(&[1004], &period),
+            Err(ScheduleGraphErrors::WorkerMissing)
+        );
+
+        assert_eq!(schedule_graph.remove_delegations(&1122334455, 20), Ok(BTreeSet::from([1003])));
+        // The `Activity` stays on its `Day`s without a crew
+        assert_eq!(schedule_graph.activity_planned_days(&1122334455, 20).unwrap(), days[2..].to_vec());
+        let supervisor_instance = schedule_graph.derive_supervisor_instance(&[1003], &period).unwrap();
+        assert!(
+            supervisor_instance.supervisor_activities[&(1122334455, 20)]
+                .delegated_technicians
+                .is_empty()
+        );
+
+        // The delegations of a `Previous` `Period` are kept
+        schedule_graph
+            .delegate_activity(vec![1003], &1122334455, 20, start_and_finish_time)
+            .unwrap();
+        system_clock.set(period.end_date().and_hms_opt(23, 0, 0).unwrap() + TimeDelta::days(1));
+        assert_eq!(
+            schedule_graph.remove_delegations(&1122334455, 20),
+            Err(ScheduleGraphErrors::PeriodPrevious)
+        );
+    }
+
+    #[test]
+    fn test_remove_delegations_with_a_locked_assignment()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let period_0 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+        let period_1 = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        schedule_graph.add_period(period_0).unwrap();
+        schedule_graph.add_period(period_1).unwrap();
+
+        let work_order = WorkOrder::new(
+            1122334455,
+            period_0.start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(16), TimeDelta::hours(16))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+
+        let availability = Availability::new(
+            period_0.start_date().and_hms_opt(7, 0, 0).unwrap(),
+            period_1.start_date().and_hms_opt(17, 0, 0).unwrap() + TimeDelta::days(1),
+        );
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+
+        // One delegation in each `Period`
+        let days = vec![period_0.start_date() + TimeDelta::days(1), period_1.start_date() + TimeDelta::days(1)];
+        schedule_graph.add_assignment_activity_days(&1122334455, 10, days.clone()).unwrap();
+        let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap());
+        for day in &days {
+            schedule_graph
+                .add_assignment_activity(vec![1001], 1122334455, 10, vec![*day], start_and_finish_time)
+                .unwrap();
+        }
+        schedule_graph.freeze_period(&period_0).unwrap();
+
+        // The unlocked delegation of the second `Period` is kept as well
+        let hyperedge_count = schedule_graph.hyperedge_count();
+        assert_eq!(
+            schedule_graph.remove_delegations(&1122334455, 10),
+            Err(ScheduleGraphErrors::AssignmentLocked)
+        );
+        assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count);
+        let supervisor_instance = schedule_graph.derive_supervisor_instance(&[1001], &period_1).unwrap();
+        assert_eq!(
+            supervisor_instance.supervisor_activities[&(1122334455, 10)].delegated_technicians,
+            BTreeSet::from([1001])
+        );
+    }
+}
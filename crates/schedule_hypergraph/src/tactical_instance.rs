@@ -0,0 +1,529 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use chrono::TimeDelta;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodState;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::ActivityRelation;
+use scheduling_environment::work_order::NumberOfPeople;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::change_log::GraphChange;
+use crate::schedule_graph::EdgeType;
+use crate::schedule_graph::Node;
+use crate::schedule_graph::NodeIndex;
+use crate::schedule_graph::ScheduleGraph;
+use crate::schedule_graph::ScheduleGraphErrors;
+
+/// The day-level view of the graph. It holds the `Activity`s of every
+/// `WorkOrder` that is assigned to one of the tactical `Period`s, so that a
+/// tactical algorithm can place them on specific `Day`s.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TacticalInstance
+{
+    /// Every `Day` of the tactical `Period`s in order.
+    pub tactical_days: Vec<NaiveDate>,
+    pub tactical_work_orders: BTreeMap<WorkOrderNumber, TacticalWorkOrder>,
+    /// Hours of every `Skill` on every `Day` summed over all `Technician`s.
+    /// A `Technician` with several `Skill`s counts towards each of them.
+    pub tactical_capacity: BTreeMap<NaiveDate, HashMap<Skill, Work>>,
+    /// Hours of every `Day` summed over all `Technician`s with a valid
+    /// `Skill`. Every `Technician` counts once, so this bounds the `Work` of
+    /// all `Skill`s together.
+    pub tactical_total_capacity: BTreeMap<NaiveDate, Work>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TacticalWorkOrder
+{
+    /// The `Period` that the `WorkOrder` is assigned to.
+    pub period: Period,
+    pub activities: BTreeMap<ActivityNumber, TacticalActivity>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TacticalActivity
+{
+    /// The `Work` of the `Activity` split by the number of people of each
+    /// `Skill` in its crew.
+    pub work_load: HashMap<Skill, Work>,
+    pub number_of_people: NumberOfPeople,
+    pub duration: TimeDelta,
+    /// The `Day` of the earliest start derived from the `BasicStart` of the
+    /// `WorkOrder` and the relations to the previous `Activity`s.
+    pub earliest_start: NaiveDate,
+    /// The `Activity`s of the same `WorkOrder` that constrain the start of
+    /// this one.
+    pub predecessors: Vec<(ActivityNumber, ActivityRelation)>,
+    /// The `Day`s that the `Activity` is already planned on or assigned to.
+    pub assigned_days: BTreeSet<NaiveDate>,
+}
+
+impl TacticalInstance
+{
+    pub fn skill_capacity(&self, day: &NaiveDate) -> HashMap<Skill, Work>
+    {
+        self.tactical_capacity.get(day).cloned().unwrap_or_default()
+    }
+
+    pub fn total_capacity(&self, day: &NaiveDate) -> Work
+    {
+        self.tactical_total_capacity.get(day).copied().unwrap_or(0.0)
+    }
+}
+
+/// Public API to derive the instance for the tactical planning.
+impl ScheduleGraph
+{
+    /// The `Period`s that the tactical planning looks at: the `Frozen`
+    /// `Period` and the `Draft` `Period`s right after it.
+    pub fn upcoming_periods(&self) -> Vec<Period>
+    {
+        self.periods()
+            .into_iter()
+            .filter(|period| {
+                matches!(
+                    self.period_state(period).expect("The Period comes from the graph"),
+                    PeriodState::Frozen | PeriodState::Draft
+                )
+            })
+            .collect()
+    }
+
+    /// Derives the `TacticalInstance` for the `WorkOrder`s that are assigned
+    /// to one of the `periods`. Completed `WorkOrder`s are left out. The
+    /// capacity of each `Day` comes from the `EdgeType::Available`
+    /// hyperedges limited by the `WorkingHoursRule`.
+    pub fn derive_tactical_instance(&self, periods: &[Period], working_hours_rule: &WorkingHoursRule)
+    -> Result<TacticalInstance, ScheduleGraphErrors>
+    {
+        let graph_periods = self.periods();
+        if periods.iter().any(|period| !graph_periods.contains(period)) {
+            return Err(ScheduleGraphErrors::PeriodMissing);
+        }
+
+        let mut tactical_periods = periods.to_vec();
+        tactical_periods.sort();
+        tactical_periods.dedup();
+
+        let tactical_days = tactical_periods
+            .iter()
+            .flat_map(|period| period.start_date().iter_days().take_while(|day| *day <= period.end_date()))
+            .collect::<Vec<_>>();
+
+        let mut tactical_work_orders = BTreeMap::new();
+        for work_order_number in self.work_order_numbers() {
+            if let Some(tactical_work_order) = self.tactical_work_order(&work_order_number, &tactical_periods)? {
+                tactical_work_orders.insert(work_order_number, tactical_work_order);
+            }
+        }
+
+        let (tactical_capacity, tactical_total_capacity) = self.day_capacity(&tactical_days, working_hours_rule);
+        Ok(TacticalInstance {
+            tactical_capacity,
+            tactical_total_capacity,
+            tactical_days,
+            tactical_work_orders,
+        })
+    }
+
+    /// Brings the `TacticalInstance` up to date with the `GraphChange`s. Only
+    /// the changed `WorkOrder`s and the capacity of the changed `Day`s are
+    /// derived again. When the horizon changed or the changes were dropped
+    /// from the change log the whole `TacticalInstance` is derived again for
+    /// the same `Period`s.
+    pub fn update_tactical_instance(
+        &self,
+        tactical_instance: &mut TacticalInstance,
+        working_hours_rule: &WorkingHoursRule,
+        graph_changes: &[GraphChange],
+    ) -> Result<(), ScheduleGraphErrors>
+    {
+        let tactical_periods = tactical_instance
+            .tactical_days
+            .iter()
+            .step_by(Period::NUMBER_OF_DAYS as usize)
+            .map(|day| Period::from_start_date(*day))
+            .collect::<Vec<_>>();
+
+        if graph_changes
+            .iter()
+            .any(|graph_change| matches!(graph_change, GraphChange::Period(_) | GraphChange::ResyncRequired))
+        {
+            *tactical_instance = self.derive_tactical_instance(&tactical_periods, working_hours_rule)?;
+            return Ok(());
+        }
+
+        let mut work_order_numbers = BTreeSet::new();
+        let mut days = BTreeSet::new();
+        for graph_change in graph_changes {
+            match graph_change {
+                GraphChange::WorkOrder(work_order_number) | GraphChange::Activity(work_order_number, _) => {
+                    work_order_numbers.insert(*work_order_number);
+                }
+                GraphChange::Day(day) if tactical_instance.tactical_days.contains(day) => {
+                    days.insert(*day);
+                }
+                // The availabilities of a `Technician` are recorded on their `Day`s
+                GraphChange::Technician(_) | GraphChange::Day(_) | GraphChange::Period(_) | GraphChange::ResyncRequired => (),
+            }
+        }
+
+        for work_order_number in work_order_numbers {
+            match self.tactical_work_order(&work_order_number, &tactical_periods)? {
+                Some(tactical_work_order) => tactical_instance.tactical_work_orders.insert(work_order_number, tactical_work_order),
+                None => tactical_instance.tactical_work_orders.remove(&work_order_number),
+            };
+        }
+
+        let days = days.into_iter().collect::<Vec<_>>();
+        for day in &days {
+            tactical_instance.tactical_capacity.remove(day);
+            tactical_instance.tactical_total_capacity.remove(day);
+        }
+        let (tactical_capacity, tactical_total_capacity) = self.day_capacity(&days, working_hours_rule);
+        tactical_instance.tactical_capacity.extend(tactical_capacity);
+        tactical_instance.tactical_total_capacity.extend(tactical_total_capacity);
+        Ok(())
+    }
+}
+
+impl ScheduleGraph
+{
+    /// `None` when the `WorkOrder` is completed or not assigned to one of
+    /// the `tactical_periods`.
+    fn tactical_work_order(
+        &self,
+        work_order_number: &WorkOrderNumber,
+        tactical_periods: &[Period],
+    ) -> Result<Option<TacticalWorkOrder>, ScheduleGraphErrors>
+    {
+        if self.is_work_order_completed(work_order_number) {
+            return Ok(None);
+        }
+        let Some(period) = self.work_order_assigned_period(work_order_number)? else {
+            return Ok(None);
+        };
+        if !tactical_periods.contains(&period) {
+            return Ok(None);
+        }
+
+        Ok(Some(TacticalWorkOrder {
+            period,
+            activities: self.tactical_activities(work_order_number)?,
+        }))
+    }
+
+    fn tactical_activities(&self, work_order_number: &WorkOrderNumber) -> Result<BTreeMap<ActivityNumber, TacticalActivity>, ScheduleGraphErrors>
+    {
+        let earliest_starts = self.activity_earliest_starts(work_order_number)?;
+
+        let mut tactical_activities = BTreeMap::new();
+        for activity_node_index in self.work_order_activities(work_order_number)? {
+            let Node::Activity(activity) = &self.nodes()[activity_node_index] else {
+                unreachable!()
+            };
+
+            let tactical_activity = TacticalActivity {
+                work_load: self.activity_work_load(activity_node_index),
+                number_of_people: activity.number_of_people(),
+                duration: activity.duration(),
+                earliest_start: earliest_starts[&activity.activity_number()].date(),
+                predecessors: self.activity_predecessors(activity_node_index),
+                assigned_days: self.activity_assigned_days(activity_node_index),
+            };
+            tactical_activities.insert(activity.activity_number(), tactical_activity);
+        }
+        Ok(tactical_activities)
+    }
+
+    /// Format: `vec![$predecessor, $successor]`
+    pub(crate) fn activity_predecessors(&self, activity_node_index: NodeIndex) -> Vec<(ActivityNumber, ActivityRelation)>
+    {
+        self.incidence_list()[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| hyperedge.nodes()[1] == activity_node_index)
+            .filter_map(|hyperedge| {
+                let activity_relation = match hyperedge.edge_type() {
+                    EdgeType::FinishStart => ActivityRelation::FinishStart,
+                    EdgeType::StartStart => ActivityRelation::StartStart,
+                    _ => return None,
+                };
+                match &self.nodes()[hyperedge.nodes()[0]] {
+                    Node::Activity(predecessor) => Some((predecessor.activity_number(), activity_relation)),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    /// Format: `vec![$activity, @days]` or `vec![$activity, @technicians,
+    /// @days]`
+    pub(crate) fn activity_assigned_days(&self, activity_node_index: NodeIndex) -> BTreeSet<NaiveDate>
+    {
+        self.incidence_list()[activity_node_index]
+            .iter()
+            .map(|&edge_index| &self.hyperedges()[edge_index])
+            .filter(|hyperedge| matches!(hyperedge.edge_type(), EdgeType::Assign(..)))
+            .flat_map(|hyperedge| hyperedge.nodes().iter())
+            .filter_map(|&node_index| match self.nodes()[node_index] {
+                Node::Day(day) => Some(day),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The hours of every `Skill` and the total hours of every `Day`. A
+    /// `Skill` only counts on the days where the `Qualification` of the
+    /// `Technician` is valid.
+    ///
+    /// Format: `vec![$technician, @skills, @days]`
+    fn day_capacity(
+        &self,
+        days: &[NaiveDate],
+        working_hours_rule: &WorkingHoursRule,
+    ) -> (BTreeMap<NaiveDate, HashMap<Skill, Work>>, BTreeMap<NaiveDate, Work>)
+    {
+        let mut day_skill_capacity: BTreeMap<NaiveDate, HashMap<Skill, Work>> = BTreeMap::new();
+        let mut day_total_capacity: BTreeMap<NaiveDate, Work> = BTreeMap::new();
+        for day in days {
+            let Ok(&day_node_index) = self.day_node_index(day) else {
+                continue;
+            };
+
+            for &edge_index in &self.incidence_list()[day_node_index] {
+                let hyperedge = &self.hyperedges()[edge_index];
+                let EdgeType::Available(availability) = hyperedge.edge_type() else {
+                    continue;
+                };
+                let working_hours = availability.working_hours(*day, working_hours_rule);
+                if working_hours == 0.0 {
+                    continue;
+                }
+
+                let skill_capacity = day_skill_capacity.entry(*day).or_default();
+                let mut is_qualified = false;
+                for &node_index in &hyperedge.nodes()[1..] {
+                    if let Node::Skill(skill) = &self.nodes()[node_index]
+                        && self.is_technician_qualified_on(hyperedge.nodes()[0], node_index, *day)
+                    {
+                        *skill_capacity.entry(skill.clone()).or_insert(0.0) += working_hours;
+                        is_qualified = true;
+                    }
+                }
+                if is_qualified {
+                    *day_total_capacity.entry(*day).or_insert(0.0) += working_hours;
+                }
+            }
+        }
+        (day_skill_capacity, day_total_capacity)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeSet;
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+    use chrono::NaiveTime;
+    use chrono::TimeDelta;
+    use scheduling_environment::Period;
+    use scheduling_environment::system_clock::MockClock;
+    use scheduling_environment::technician::Availability;
+    use scheduling_environment::technician::Proficiency;
+    use scheduling_environment::technician::Qualification;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::technician::SkillCatalog;
+    use scheduling_environment::technician::Technician;
+    use scheduling_environment::technician::WorkingHoursRule;
+    use scheduling_environment::work_order::Activity;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::WorkOrder;
+
+    use crate::schedule_graph::ScheduleGraph;
+    use crate::schedule_graph::ScheduleGraphErrors;
+
+    #[test]
+    fn test_derive_tactical_instance()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 8).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let periods = [(1, 6), (1, 20), (2, 3)]
+            .into_iter()
+            .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech"), Skill::new("MtnElec")]));
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+
+        // The `Draft2` `Period` is not upcoming
+        assert_eq!(schedule_graph.upcoming_periods(), periods[..2].to_vec());
+
+        let basic_start = NaiveDate::from_ymd_opt(2025, 1, 9).unwrap();
+        let work_order = WorkOrder::new(
+            1122334455,
+            basic_start,
+            vec![
+                Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8)),
+                Activity::new(20, 2, Skill::new("MtnElec"), TimeDelta::hours(24), TimeDelta::hours(12)),
+            ],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+        for work_order_number in [1122334456, 1122334457] {
+            let work_order = WorkOrder::new(work_order_number, basic_start, vec![]).unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+        schedule_graph.add_assignment_work_order_period(&1122334455, &periods[1]).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334456, &periods[2]).unwrap();
+
+        let day = NaiveDate::from_ymd_opt(2025, 1, 21).unwrap();
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .add_skill(Skill::new("MtnElec"))
+            .build();
+        schedule_graph.add_technician(technician, availability.clone()).unwrap();
+        // The certificate of the second `Technician` has expired
+        let expired_qualification = Qualification::new(Proficiency::Certified)
+            .valid_between(basic_start, day - TimeDelta::days(1))
+            .unwrap();
+        let technician = Technician::builder(1002)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_qualified_skill(Skill::new("MtnElec"), expired_qualification)
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+        schedule_graph
+            .add_assignment_activity(
+                vec![1001],
+                1122334455,
+                10,
+                vec![day],
+                (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap()),
+            )
+            .unwrap();
+
+        let tactical_instance = schedule_graph
+            .derive_tactical_instance(&schedule_graph.upcoming_periods(), &WorkingHoursRule::default())
+            .unwrap();
+
+        assert_eq!(tactical_instance.tactical_days.len(), 28);
+        assert_eq!(tactical_instance.tactical_days[0], periods[0].start_date());
+        assert_eq!(tactical_instance.tactical_days[27], periods[1].end_date());
+
+        // Only the `WorkOrder` assigned to an upcoming `Period` is included
+        assert_eq!(tactical_instance.tactical_work_orders.keys().collect::<Vec<_>>(), vec![&1122334455]);
+        let tactical_work_order = &tactical_instance.tactical_work_orders[&1122334455];
+        assert_eq!(tactical_work_order.period, periods[1]);
+
+        let first_activity = &tactical_work_order.activities[&10];
+        assert_eq!(first_activity.work_load, HashMap::from([(Skill::new("MtnMech"), 8.0)]));
+        assert_eq!(first_activity.earliest_start, basic_start);
+        assert!(first_activity.predecessors.is_empty());
+        assert_eq!(first_activity.assigned_days, BTreeSet::from([day]));
+
+        let second_activity = &tactical_work_order.activities[&20];
+        assert_eq!(second_activity.number_of_people, 2);
+        assert_eq!(second_activity.duration, TimeDelta::hours(12));
+        assert_eq!(second_activity.predecessors, vec![(10, ActivityRelation::FinishStart)]);
+        assert!(second_activity.assigned_days.is_empty());
+
+        assert_eq!(tactical_instance.tactical_capacity.len(), 1);
+        assert_eq!(
+            tactical_instance.skill_capacity(&day),
+            HashMap::from([(Skill::new("MtnMech"), 8.0), (Skill::new("MtnElec"), 8.0)])
+        );
+        assert!(tactical_instance.skill_capacity(&basic_start).is_empty());
+        // The hours of the `Technician` with two `Skill`s only count once and
+        // the `Technician` with the expired certificate does not count
+        assert_eq!(tactical_instance.total_capacity(&day), 8.0);
+        assert_eq!(tactical_instance.total_capacity(&basic_start), 0.0);
+
+        let unknown_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 3, 3).unwrap());
+        assert_eq!(
+            schedule_graph.derive_tactical_instance(&[unknown_period], &WorkingHoursRule::default()),
+            Err(ScheduleGraphErrors::PeriodMissing)
+        );
+    }
+
+    #[test]
+    fn test_update_tactical_instance()
+    {
+        let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 8).unwrap().and_hms_opt(0, 0, 0).unwrap());
+        let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+        let periods = [(1, 6), (1, 20)]
+            .into_iter()
+            .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+            .collect::<Vec<_>>();
+        schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+        for period in &periods {
+            schedule_graph.add_period(*period).unwrap();
+        }
+        for work_order_number in [1122334455, 1122334456] {
+            let work_order = WorkOrder::new(
+                work_order_number,
+                periods[1].start_date(),
+                vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+            )
+            .unwrap();
+            schedule_graph.add_work_order(&work_order).unwrap();
+        }
+        schedule_graph.add_assignment_work_order_period(&1122334455, &periods[1]).unwrap();
+
+        let working_hours_rule = WorkingHoursRule::default();
+        let mut tactical_instance = schedule_graph.derive_tactical_instance(&periods, &working_hours_rule).unwrap();
+        let mut change_subscription = schedule_graph.subscribe();
+
+        let day = NaiveDate::from_ymd_opt(2025, 1, 21).unwrap();
+        let availability = Availability::new(day.and_hms_opt(7, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+        let technician = Technician::builder(1001)
+            .add_availability(availability.start(), availability.end())
+            .unwrap()
+            .add_skill(Skill::new("MtnMech"))
+            .build();
+        schedule_graph.add_technician(technician, availability).unwrap();
+        schedule_graph.add_assignment_activity_days(&1122334455, 10, vec![day]).unwrap();
+        schedule_graph.add_assignment_work_order_period(&1122334456, &periods[1]).unwrap();
+
+        schedule_graph
+            .update_tactical_instance(&mut tactical_instance, &working_hours_rule, change_subscription.poll(&schedule_graph))
+            .unwrap();
+        assert_eq!(tactical_instance.tactical_work_orders.len(), 2);
+        assert_eq!(
+            tactical_instance.tactical_work_orders[&1122334455].activities[&10].assigned_days,
+            BTreeSet::from([day])
+        );
+        assert_eq!(tactical_instance.skill_capacity(&day), HashMap::from([(Skill::new("MtnMech"), 8.0)]));
+        assert_eq!(tactical_instance.total_capacity(&day), 8.0);
+        assert_eq!(
+            tactical_instance,
+            schedule_graph.derive_tactical_instance(&periods, &working_hours_rule).unwrap()
+        );
+
+        schedule_graph.complete_work_order(&1122334455).unwrap();
+        schedule_graph.remove_assignments_work_order(&1122334456).unwrap();
+        schedule_graph
+            .update_tactical_instance(&mut tactical_instance, &working_hours_rule, change_subscription.poll(&schedule_graph))
+            .unwrap();
+        assert!(tactical_instance.tactical_work_orders.is_empty());
+        assert_eq!(
+            tactical_instance,
+            schedule_graph.derive_tactical_instance(&periods, &working_hours_rule).unwrap()
+        );
+    }
+}
@@ -3,10 +3,12 @@ use std::path::PathBuf;
 
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use chrono::TimeDelta;
 use schedule_hypergraph::schedule_graph::ScheduleGraph;
 use scheduling_environment::Period;
 use scheduling_environment::technician::Availability;
 use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillCatalog;
 use scheduling_environment::technician::Technician;
 use scheduling_environment::work_order::WorkOrder;
 use serde::Deserialize;
@@ -35,6 +37,10 @@ fn get_test_data_path(filename: &str) -> PathBuf
 #[test]
 fn test_large_scale_hypergraph()
 {
+    // Read the skill catalog from JSON
+    let skills_json = fs::read_to_string(get_test_data_path("skills.json")).expect("Failed to read skills.json");
+    let skill_catalog: SkillCatalog = serde_json::from_str(&skills_json).expect("Failed to parse skills.json");
+
     // Read periods from JSON
     let periods_json = fs::read_to_string(get_test_data_path("periods.json")).expect("Failed to read periods.json");
     let period_dates: Vec<NaiveDate> = serde_json::from_str(&periods_json).expect("Failed to parse periods.json");
@@ -47,6 +53,7 @@ fn test_large_scale_hypergraph()
     let technicians_json = fs::read_to_string(get_test_data_path("technicians.json")).expect("Failed to read technicians.json");
     let technician_data: Vec<TechnicianData> = serde_json::from_str(&technicians_json).expect("Failed to parse technicians.json");
 
+    println!("Loaded {} skills", skill_catalog.len());
     println!("Loaded {} periods", period_dates.len());
     println!("Loaded {} work orders", work_orders.len());
     println!("Loaded {} technicians", technician_data.len());
@@ -54,9 +61,8 @@ fn test_large_scale_hypergraph()
     // Create the schedule graph
     let mut schedule_graph = ScheduleGraph::new();
 
-    // First, add all skills as nodes
-    schedule_graph.add_skill(Skill::MtnMech);
-    schedule_graph.add_skill(Skill::MtnElec);
+    // First, add all skills of the catalog as nodes
+    schedule_graph.add_skill_catalog(&skill_catalog);
 
     // Add all periods (this also creates day nodes)
     for period_date in &period_dates {
@@ -86,7 +92,7 @@ fn test_large_scale_hypergraph()
         let mut builder = Technician::builder(tech_data.id);
 
         for skill in &tech_data.skills {
-            builder = builder.add_skill(*skill);
+            builder = builder.add_skill(skill.clone());
         }
 
         // We need to add only the first availability that's valid for the graph
@@ -118,9 +124,25 @@ fn test_large_scale_hypergraph()
     println!("  Total hyperedges: {}", hyperedge_count);
 
     // Basic assertions
+    assert!(skill_catalog.len() == 7, "Expected 7 skills, got {}", skill_catalog.len());
+    assert!(
+        work_orders
+            .iter()
+            .flat_map(|work_order| work_order.activities())
+            .flat_map(|activity| activity.requirements())
+            .all(|requirement| skill_catalog.contains(requirement.skill())),
+        "Every activity should require a skill from the catalog"
+    );
     assert!(period_dates.len() == 52, "Expected 52 periods, got {}", period_dates.len());
     assert!(work_orders.len() == 1000, "Expected 1000 work orders, got {}", work_orders.len());
     assert!(technician_data.len() == 100, "Expected 100 technicians, got {}", technician_data.len());
+    assert!(
+        work_orders
+            .iter()
+            .flat_map(|work_order| work_order.activities())
+            .all(|activity| activity.duration() > TimeDelta::zero() && activity.work() <= activity.duration() * activity.number_of_people() as i32),
+        "Every activity should have a duration that fits its work"
+    );
 
     // Verify graph is populated
     assert!(node_count > 0, "Graph should have nodes");
@@ -1,7 +1,9 @@
+use chrono::Days;
 use chrono::NaiveDate;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub mod system_clock;
 pub mod technician;
 pub mod work_order;
 
@@ -10,6 +12,9 @@ pub struct Period(NaiveDate);
 
 impl Period
 {
+    /// Every `Period` spans two weeks
+    pub const NUMBER_OF_DAYS: u64 = 14;
+
     pub fn from_start_date(start_date: NaiveDate) -> Self
     {
         Self(start_date)
@@ -19,4 +24,76 @@ impl Period
     {
         self.0
     }
+
+    /// Last day of the `Period` (inclusive)
+    pub fn end_date(&self) -> NaiveDate
+    {
+        self.0 + Days::new(Self::NUMBER_OF_DAYS - 1)
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool
+    {
+        self.start_date() <= date && date <= self.end_date()
+    }
+
+    /// Where the `Period` is in its lifecycle on the given day.
+    pub fn period_state(&self, today: NaiveDate) -> PeriodState
+    {
+        if self.end_date() < today {
+            PeriodState::Previous
+        } else if self.contains(today) {
+            PeriodState::Frozen
+        } else if self.start_date() <= today + Days::new(Self::NUMBER_OF_DAYS) {
+            PeriodState::Draft
+        } else {
+            PeriodState::Draft2
+        }
+    }
+}
+
+/// The lifecycle of a `Period` as time passes.
+#[derive(Hash, Copy, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum PeriodState
+{
+    /// The `Period` is over and may not be changed anymore.
+    Previous,
+    /// The `Period` is being carried out. Only the day to day planning may
+    /// change it.
+    Frozen,
+    /// The next `Period`
+    Draft,
+    /// Every `Period` after the next one
+    Draft2,
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+
+    use super::Period;
+    use super::PeriodState;
+
+    fn date(month: u32, day: u32) -> NaiveDate
+    {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_period_state()
+    {
+        let period = Period::from_start_date(date(1, 13));
+        assert_eq!(period.end_date(), date(1, 26));
+
+        // The `Period` becomes the next one 14 days before it starts
+        assert_eq!(period.period_state(NaiveDate::from_ymd_opt(2024, 12, 29).unwrap()), PeriodState::Draft2);
+        assert_eq!(period.period_state(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()), PeriodState::Draft);
+        assert_eq!(period.period_state(date(1, 12)), PeriodState::Draft);
+
+        assert_eq!(period.period_state(date(1, 13)), PeriodState::Frozen);
+        assert_eq!(period.period_state(date(1, 26)), PeriodState::Frozen);
+
+        // The day after the last day of the `Period`
+        assert_eq!(period.period_state(date(1, 27)), PeriodState::Previous);
+    }
 }
@@ -0,0 +1,105 @@
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use chrono::Local;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeDelta;
+
+/// Source of the current time. Everything that depends on what "now" is
+/// should ask a `SystemClock` so that tests can control time.
+pub trait SystemClock: Debug + Send + Sync
+{
+    fn now(&self) -> NaiveDateTime;
+
+    fn today(&self) -> NaiveDate
+    {
+        self.now().date()
+    }
+}
+
+/// The local time of the machine
+#[derive(Default, Debug, Clone, Copy)]
+pub struct RealClock;
+
+impl SystemClock for RealClock
+{
+    fn now(&self) -> NaiveDateTime
+    {
+        Local::now().naive_local()
+    }
+}
+
+/// A `SystemClock` that only moves when it is told to. Clones share the same
+/// time, so a test can keep a clone and move the clock of a value that owns
+/// the other one.
+#[derive(Debug, Clone)]
+pub struct MockClock(Arc<Mutex<NaiveDateTime>>);
+
+impl MockClock
+{
+    pub fn new(now: NaiveDateTime) -> Self
+    {
+        Self(Arc::new(Mutex::new(now)))
+    }
+
+    pub fn set(&self, now: NaiveDateTime)
+    {
+        *self.0.lock().unwrap() = now;
+    }
+
+    pub fn advance(&self, time_delta: TimeDelta)
+    {
+        *self.0.lock().unwrap() += time_delta;
+    }
+}
+
+impl SystemClock for MockClock
+{
+    fn now(&self) -> NaiveDateTime
+    {
+        *self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+
+    use super::MockClock;
+    use super::SystemClock;
+
+    #[test]
+    fn test_mock_clock()
+    {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 26).unwrap().and_hms_opt(23, 0, 0).unwrap();
+        let mock_clock = MockClock::new(start);
+        assert_eq!(mock_clock.now(), start);
+        assert_eq!(mock_clock.today(), start.date());
+
+        // Advancing past midnight moves to the next day
+        mock_clock.advance(TimeDelta::hours(1));
+        assert_eq!(mock_clock.now(), start + TimeDelta::hours(1));
+        assert_eq!(mock_clock.today(), NaiveDate::from_ymd_opt(2025, 1, 27).unwrap());
+
+        mock_clock.set(start);
+        assert_eq!(mock_clock.now(), start);
+    }
+
+    #[test]
+    fn test_mock_clock_clones_share_the_time()
+    {
+        let start = NaiveDate::from_ymd_opt(2025, 1, 13).unwrap().and_hms_opt(7, 0, 0).unwrap();
+        let mock_clock = MockClock::new(start);
+        let system_clock: Box<dyn SystemClock> = Box::new(mock_clock.clone());
+
+        mock_clock.advance(TimeDelta::days(14));
+        assert_eq!(system_clock.now(), start + TimeDelta::days(14));
+
+        mock_clock.set(start);
+        assert_eq!(system_clock.today(), start.date());
+    }
+}
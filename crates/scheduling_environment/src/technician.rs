@@ -1,15 +1,211 @@
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use chrono::Datelike;
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use chrono::Weekday;
 use serde::Deserialize;
 use serde::Serialize;
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
-pub enum Skill
+use crate::work_order::Work;
+use crate::work_order::work_hours;
+
+/// A craft that a `Technician` can hold and an `Activity` can require. The
+/// available skills are not known at compile time, they are defined by the
+/// [`SkillCatalog`]. The name of a `Skill` is never empty.
+#[derive(Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Skill(String);
+
+impl Skill
+{
+    /// # Panics
+    ///
+    /// If the name is empty. Use `Skill::try_from` for names that are not
+    /// known to be valid.
+    pub fn new(name: impl Into<String>) -> Self
+    {
+        Self::try_from(name.into()).expect("The name of a `Skill` can not be empty")
+    }
+
+    pub fn name(&self) -> &str
+    {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for Skill
 {
-    MtnMech,
-    MtnElec,
+    type Error = SkillError;
+
+    fn try_from(name: String) -> Result<Self, Self::Error>
+    {
+        if name.is_empty() {
+            return Err(SkillError::EmptyName);
+        }
+        Ok(Self(name))
+    }
+}
+
+impl From<Skill> for String
+{
+    fn from(skill: Skill) -> Self
+    {
+        skill.0
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum SkillError
+{
+    EmptyName,
+}
+
+impl std::fmt::Display for SkillError
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self {
+            SkillError::EmptyName => write!(f, "the name of a skill can not be empty"),
+        }
+    }
+}
+
+/// Every `Skill` known to the scheduling system, keyed by name. The catalog
+/// is loaded from configuration, e.g. a JSON array of skill names, or
+/// collected from the skills found in the input files.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "Vec<Skill>", into = "Vec<Skill>")]
+pub struct SkillCatalog(BTreeMap<String, Skill>);
+
+impl SkillCatalog
+{
+    pub fn new() -> Self
+    {
+        Self(BTreeMap::new())
+    }
+
+    /// Returns `false` if the `Skill` was already in the catalog
+    pub fn insert(&mut self, skill: Skill) -> bool
+    {
+        self.0.insert(skill.name().to_string(), skill).is_none()
+    }
+
+    pub fn contains(&self, skill: &Skill) -> bool
+    {
+        self.0.contains_key(skill.name())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Skill>
+    {
+        self.0.get(name)
+    }
+
+    pub fn skills(&self) -> impl Iterator<Item = &Skill>
+    {
+        self.0.values()
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<Skill> for SkillCatalog
+{
+    fn from_iter<T: IntoIterator<Item = Skill>>(iter: T) -> Self
+    {
+        Self(iter.into_iter().map(|skill| (skill.name().to_string(), skill)).collect())
+    }
+}
+
+impl From<Vec<Skill>> for SkillCatalog
+{
+    fn from(skills: Vec<Skill>) -> Self
+    {
+        skills.into_iter().collect()
+    }
+}
+
+impl From<SkillCatalog> for Vec<Skill>
+{
+    fn from(skill_catalog: SkillCatalog) -> Self
+    {
+        skill_catalog.0.into_values().collect()
+    }
+}
+
+/// The level at which a `Technician` holds a `Skill`. The ordering is
+/// significant: a higher level satisfies every requirement of a lower one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
+pub enum Proficiency
+{
+    #[default]
+    Apprentice,
+    Journeyman,
+    Certified,
+}
+
+/// How a `Technician` holds a `Skill`. Certificates can expire, so the
+/// qualification can be limited to a range of dates (both inclusive).
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Hash, Serialize, Deserialize)]
+pub struct Qualification
+{
+    proficiency: Proficiency,
+    validity: Option<(NaiveDate, NaiveDate)>,
+}
+
+impl Qualification
+{
+    pub fn new(proficiency: Proficiency) -> Self
+    {
+        Self { proficiency, validity: None }
+    }
+
+    pub fn valid_between(mut self, valid_from: NaiveDate, valid_to: NaiveDate) -> Result<Self, QualificationError>
+    {
+        if valid_to < valid_from {
+            return Err(QualificationError::ValidToBeforeValidFrom { valid_from, valid_to });
+        }
+        self.validity = Some((valid_from, valid_to));
+        Ok(self)
+    }
+
+    pub fn proficiency(&self) -> Proficiency
+    {
+        self.proficiency
+    }
+
+    pub fn validity(&self) -> Option<(NaiveDate, NaiveDate)>
+    {
+        self.validity
+    }
+
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool
+    {
+        match self.validity {
+            Some((valid_from, valid_to)) => valid_from <= date && date <= valid_to,
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum QualificationError
+{
+    ValidToBeforeValidFrom
+    {
+        valid_from: NaiveDate, valid_to: NaiveDate
+    },
 }
 
 #[derive(Debug, PartialEq)]
@@ -54,6 +250,25 @@ impl Availability
         self.1.date()
     }
 
+    /// Working hours of the availability on a single `date`. This is the
+    /// time that the availability covers on the `date` capped by the
+    /// `WorkingHoursRule`.
+    pub fn working_hours(&self, date: NaiveDate, working_hours_rule: &WorkingHoursRule) -> Work
+    {
+        if working_hours_rule.days_off.contains(&date.weekday()) {
+            return 0.0;
+        }
+
+        let day_start = date.and_time(NaiveTime::MIN);
+        let start = self.0.max(day_start);
+        let end = self.1.min(day_start + TimeDelta::days(1));
+        if end <= start {
+            return 0.0;
+        }
+
+        work_hours(end - start).min(working_hours_rule.hours_per_day)
+    }
+
     /// Check if this availability overlaps with another
     pub fn overlaps_with(&self, other: &Availability) -> bool
     {
@@ -62,6 +277,43 @@ impl Availability
     }
 }
 
+/// Rule for how much of an `Availability` counts as working hours
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WorkingHoursRule
+{
+    hours_per_day: Work,
+    days_off: Vec<Weekday>,
+}
+
+impl WorkingHoursRule
+{
+    pub fn new(hours_per_day: Work, days_off: Vec<Weekday>) -> Self
+    {
+        Self { hours_per_day, days_off }
+    }
+
+    pub fn hours_per_day(&self) -> Work
+    {
+        self.hours_per_day
+    }
+
+    pub fn days_off(&self) -> &[Weekday]
+    {
+        &self.days_off
+    }
+}
+
+impl Default for WorkingHoursRule
+{
+    fn default() -> Self
+    {
+        Self {
+            hours_per_day: 8.0,
+            days_off: vec![],
+        }
+    }
+}
+
 // Implement ordering traits for BTreeSet
 impl PartialOrd for Availability
 {
@@ -84,14 +336,14 @@ pub struct Technician
 {
     technician_id: usize,
     availabilities: BTreeSet<Availability>,
-    skills: BTreeSet<Skill>,
+    skills: BTreeMap<Skill, Qualification>,
 }
 
 pub struct TechnicianBuilder
 {
     technician_id: usize,
     availabilities: BTreeSet<Availability>,
-    skills: BTreeSet<Skill>,
+    skills: BTreeMap<Skill, Qualification>,
 }
 
 impl TechnicianBuilder
@@ -101,7 +353,7 @@ impl TechnicianBuilder
         Self {
             technician_id,
             availabilities: BTreeSet::new(),
-            skills: BTreeSet::new(),
+            skills: BTreeMap::new(),
         }
     }
 
@@ -125,9 +377,15 @@ impl TechnicianBuilder
         Ok(self)
     }
 
-    pub fn add_skill(mut self, skill: Skill) -> Self
+    /// Adds a `Skill` without any expiry at the lowest `Proficiency`
+    pub fn add_skill(self, skill: Skill) -> Self
     {
-        self.skills.insert(skill);
+        self.add_qualified_skill(skill, Qualification::default())
+    }
+
+    pub fn add_qualified_skill(mut self, skill: Skill, qualification: Qualification) -> Self
+    {
+        self.skills.insert(skill, qualification);
         self
     }
 
@@ -154,6 +412,11 @@ impl Technician
     }
 
     pub fn skills(&self) -> Vec<&Skill>
+    {
+        self.skills.keys().collect()
+    }
+
+    pub fn qualifications(&self) -> Vec<(&Skill, &Qualification)>
     {
         self.skills.iter().collect()
     }
@@ -175,3 +438,65 @@ impl Technician
 //     These will be handled by the relationships in the Graph.
 //     assigned_activities: Vec<AssignedWork>,
 // }
+
+#[cfg(test)]
+mod tests
+{
+    use chrono::NaiveDate;
+
+    use super::Proficiency;
+    use super::Qualification;
+    use super::QualificationError;
+    use super::Skill;
+    use super::SkillCatalog;
+    use super::SkillError;
+
+    #[test]
+    fn test_skill_name_can_not_be_empty()
+    {
+        assert_eq!(Skill::try_from(String::new()), Err(SkillError::EmptyName));
+        assert_eq!(Skill::try_from("MtnMech".to_string()), Ok(Skill::new("MtnMech")));
+
+        assert!(serde_json::from_str::<Skill>("\"\"").is_err());
+        assert!(serde_json::from_str::<SkillCatalog>(r#"["MtnMech", ""]"#).is_err());
+    }
+
+    #[test]
+    fn test_skill_catalog()
+    {
+        let mut skill_catalog: SkillCatalog = serde_json::from_str(r#"["MtnMech", "MtnElec", "MtnMech"]"#).unwrap();
+        assert_eq!(skill_catalog.len(), 2);
+        assert_eq!(skill_catalog.get("MtnElec"), Some(&Skill::new("MtnElec")));
+        assert_eq!(skill_catalog.get("MtnScaf"), None);
+
+        assert!(skill_catalog.insert(Skill::new("MtnScaf")));
+        assert!(!skill_catalog.insert(Skill::new("MtnScaf")));
+        assert!(skill_catalog.contains(&Skill::new("MtnScaf")));
+
+        assert_eq!(serde_json::to_string(&skill_catalog).unwrap(), r#"["MtnElec","MtnMech","MtnScaf"]"#);
+    }
+
+    #[test]
+    fn test_qualification_valid_between()
+    {
+        let valid_from = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+        let valid_to = NaiveDate::from_ymd_opt(2025, 1, 19).unwrap();
+
+        let qualification = Qualification::new(Proficiency::Certified).valid_between(valid_from, valid_to).unwrap();
+        assert!(!qualification.is_valid_on(valid_from.pred_opt().unwrap()));
+        assert!(qualification.is_valid_on(valid_from));
+        assert!(qualification.is_valid_on(valid_to));
+        assert!(!qualification.is_valid_on(valid_to.succ_opt().unwrap()));
+
+        // A single day is a valid range
+        assert!(Qualification::new(Proficiency::Certified).valid_between(valid_to, valid_to).is_ok());
+
+        assert_eq!(
+            Qualification::new(Proficiency::Certified).valid_between(valid_to, valid_from),
+            Err(QualificationError::ValidToBeforeValidFrom {
+                valid_from: valid_to,
+                valid_to: valid_from,
+            })
+        );
+    }
+}
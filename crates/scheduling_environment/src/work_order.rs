@@ -5,6 +5,7 @@ use chrono::TimeDelta;
 use serde::Deserialize;
 use serde::Serialize;
 
+use crate::technician::Proficiency;
 use crate::technician::Skill;
 
 pub type WorkOrderNumber = u64;
@@ -12,12 +13,70 @@ pub type NumberOfPeople = u64;
 
 pub type ActivityNumber = u64;
 pub type Work = f64;
-#[derive(Hash, Clone, Copy, Debug, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+
+/// Converts crew time into `Work` measured in hours.
+pub fn work_hours(time_delta: TimeDelta) -> Work
+{
+    time_delta.num_seconds() as Work / 3600.0
+}
+
+/// The crew of a single `Skill` that an `Activity` needs, e.g. two
+/// mechanics.
+#[derive(Hash, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub struct SkillRequirement
+{
+    skill: Skill,
+    number_of_people: NumberOfPeople,
+    /// Lowest `Proficiency` of the `Skill` that is allowed to fill the
+    /// requirement.
+    #[serde(default)]
+    minimum_proficiency: Proficiency,
+}
+
+impl SkillRequirement
+{
+    pub fn new(skill: Skill, number_of_people: NumberOfPeople) -> Self
+    {
+        Self {
+            skill,
+            number_of_people,
+            minimum_proficiency: Proficiency::default(),
+        }
+    }
+
+    pub fn with_minimum_proficiency(mut self, minimum_proficiency: Proficiency) -> Self
+    {
+        self.minimum_proficiency = minimum_proficiency;
+        self
+    }
+
+    pub fn skill(&self) -> &Skill
+    {
+        &self.skill
+    }
+
+    pub fn number_of_people(&self) -> NumberOfPeople
+    {
+        self.number_of_people
+    }
+
+    pub fn minimum_proficiency(&self) -> Proficiency
+    {
+        self.minimum_proficiency
+    }
+}
+
+#[derive(Hash, Clone, Debug, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
 pub struct Activity
 {
     activity_number: ActivityNumber,
-    number_of_people: NumberOfPeople,
-    resource: Skill,
+    requirements: Vec<SkillRequirement>,
+    /// Planned work of the whole crew. Serialized in hours.
+    #[serde(with = "hours")]
+    work: TimeDelta,
+    /// Planned elapsed time of the activity. Serialized in hours.
+    #[serde(with = "hours")]
+    duration: TimeDelta,
 }
 
 impl Activity
@@ -27,34 +86,126 @@ impl Activity
         self.activity_number
     }
 
-    pub fn skill(&self) -> Skill
+    pub fn requirements(&self) -> &[SkillRequirement]
     {
-        self.resource
+        &self.requirements
     }
 
+    /// Size of the whole crew across every `SkillRequirement`
     pub fn number_of_people(&self) -> NumberOfPeople
     {
-        self.number_of_people
+        self.requirements.iter().map(SkillRequirement::number_of_people).sum()
+    }
+
+    pub fn work(&self) -> TimeDelta
+    {
+        self.work
+    }
+
+    pub fn duration(&self) -> TimeDelta
+    {
+        self.duration
+    }
+
+    /// The crew of the activity has to be able to perform the planned
+    /// work inside of the planned duration.
+    pub fn is_valid(&self) -> bool
+    {
+        self.work >= TimeDelta::zero() && self.duration > TimeDelta::zero() && self.work <= self.duration * self.number_of_people() as i32
     }
 }
 
 impl Activity
 {
-    pub fn new(activity_number: u64, number_of_people: NumberOfPeople, resource: Skill) -> Self
+    /// An `Activity` that only requires a single `Skill`
+    pub fn new(activity_number: u64, number_of_people: NumberOfPeople, resource: Skill, work: TimeDelta, duration: TimeDelta) -> Self
+    {
+        Self::with_requirements(activity_number, vec![SkillRequirement::new(resource, number_of_people)], work, duration)
+    }
+
+    pub fn with_requirements(activity_number: u64, requirements: Vec<SkillRequirement>, work: TimeDelta, duration: TimeDelta) -> Self
     {
         Self {
             activity_number,
-            resource,
-            number_of_people,
+            requirements,
+            work,
+            duration,
+        }
+    }
+
+    /// Sets the minimum `Proficiency` of every `SkillRequirement`
+    pub fn with_minimum_proficiency(mut self, minimum_proficiency: Proficiency) -> Self
+    {
+        for requirement in &mut self.requirements {
+            requirement.minimum_proficiency = minimum_proficiency;
+        }
+        self
+    }
+}
+
+/// How urgently the `WorkOrder` has to be carried out.
+#[derive(Hash, Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum Priority
+{
+    Emergency,
+    High,
+    Medium,
+    #[default]
+    Low,
+}
+
+/// How critical the equipment of the `WorkOrder` is for the plant.
+#[derive(Hash, Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum Criticality
+{
+    High,
+    Medium,
+    #[default]
+    Low,
+}
+
+#[derive(Hash, Copy, Clone, Debug, Default, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
+pub enum OrderType
+{
+    Preventive,
+    #[default]
+    Corrective,
+    Modification,
+}
+
+/// The attributes of a `WorkOrder` that the planning uses to decide which
+/// `WorkOrder`s are the most important to schedule.
+#[derive(Hash, Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkOrderInfo
+{
+    pub priority: Priority,
+    pub criticality: Criticality,
+    pub order_type: OrderType,
+}
+
+impl WorkOrderInfo
+{
+    pub fn new(priority: Priority, criticality: Criticality, order_type: OrderType) -> Self
+    {
+        Self {
+            priority,
+            criticality,
+            order_type,
         }
     }
 }
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WorkOrder
 {
     work_order_number: WorkOrderNumber,
     basic_start_date: NaiveDate,
     activities: Vec<Activity>,
+    /// Last day on which the `WorkOrder` is allowed to finish
+    #[serde(default)]
+    latest_finish_date: Option<NaiveDate>,
+    #[serde(default)]
+    work_order_info: WorkOrderInfo,
 }
 
 #[derive(Debug)]
@@ -63,6 +214,8 @@ pub enum WorkOrderError
     InvalidWorkOrderNumber(String),
     NonSortedActivities(Vec<Activity>),
     DuplicatedActivities,
+    InvalidActivityWork(ActivityNumber),
+    LatestFinishBeforeBasicStart(NaiveDate),
 }
 
 impl WorkOrder
@@ -81,13 +234,34 @@ impl WorkOrder
             return Err(WorkOrderError::DuplicatedActivities);
         }
 
+        if let Some(activity) = activities.iter().find(|activity| !activity.is_valid()) {
+            return Err(WorkOrderError::InvalidActivityWork(activity.activity_number()));
+        }
+
         Ok(Self {
             work_order_number,
             activities,
             basic_start_date,
+            latest_finish_date: None,
+            work_order_info: WorkOrderInfo::default(),
         })
     }
 
+    pub fn with_latest_finish(mut self, latest_finish_date: NaiveDate) -> Result<Self, WorkOrderError>
+    {
+        if latest_finish_date < self.basic_start_date {
+            return Err(WorkOrderError::LatestFinishBeforeBasicStart(latest_finish_date));
+        }
+        self.latest_finish_date = Some(latest_finish_date);
+        Ok(self)
+    }
+
+    pub fn with_work_order_info(mut self, work_order_info: WorkOrderInfo) -> Self
+    {
+        self.work_order_info = work_order_info;
+        self
+    }
+
     pub fn work_order_number(&self) -> WorkOrderNumber
     {
         self.work_order_number
@@ -107,10 +281,48 @@ impl WorkOrder
     {
         self.basic_start_date
     }
+
+    pub fn latest_finish(&self) -> Option<NaiveDate>
+    {
+        self.latest_finish_date
+    }
+
+    pub fn work_order_info(&self) -> &WorkOrderInfo
+    {
+        &self.work_order_info
+    }
 }
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ActivityRelation
 {
     StartStart,
     FinishStart,
     Postpone(TimeDelta),
 }
+
+/// `TimeDelta`s of the input files are written as fractional hours.
+mod hours
+{
+    use chrono::TimeDelta;
+    use serde::Deserialize;
+    use serde::Deserializer;
+    use serde::Serialize;
+    use serde::Serializer;
+
+    use super::Work;
+    use super::work_hours;
+
+    pub(crate) fn serialize<S: Serializer>(time_delta: &TimeDelta, serializer: S) -> Result<S::Ok, S::Error>
+    {
+        work_hours(*time_delta).serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TimeDelta, D::Error>
+    {
+        let hours = Work::deserialize(deserializer)?;
+        if !hours.is_finite() {
+            return Err(serde::de::Error::custom("hours have to be finite"));
+        }
+        Ok(TimeDelta::seconds((hours * 3600.0).round() as i64))
+    }
+}
@@ -1,31 +1,40 @@
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-use scheduling_environment::Period;
+use schedule_hypergraph::change_log::GraphChange;
 use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
 use schedule_hypergraph::schedule_graph::TechnicianId;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodState;
 use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::WorkingHoursRule;
 use scheduling_environment::work_order::Work;
 use scheduling_environment::work_order::WorkOrderNumber;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::strategic_options::StrategicOptions;
 
-#[derive(Debug)]
+pub mod strategic_exchange;
+pub mod strategic_model;
+pub mod strategic_options;
+pub mod strategic_solution;
+pub mod strategic_solver;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct StrategicInstance
 {
     pub strategic_work_order_parameters: HashMap<WorkOrderNumber, WorkOrderParameter>,
     // This comes from technician availability
     pub strategic_capacity: StrategicResources,
     // pub strategic_clustering: StrategicClustering,
-    // This comes from the `assignment`.
+    // This comes from the `assignment`. A `Period` is locked when it was
+    // frozen, when it holds locked assignments of `Activity`s to its days or
+    // when its `PeriodState` from the [`SystemClock`] is `Previous` or
+    // `Frozen`.
     pub period_locks: HashSet<Period>,
-    // TODO #04 #00 #01
-    // enum PeriodState {
-    //     Previous(Period),
-    //     Frozen(Period),
-    //     Draft(Period),
-    //     Draft2(Period),
-    // }
-    // Create this and have it change based on the value
-    // of the [`SystemClock`].
     pub strategic_periods: Vec<Period>,
     // TODO [ ] Should the options be here? Yes they, no they should not.
     // WARN [ ] Now you know why!
@@ -36,7 +45,7 @@ pub struct StrategicInstance
 // now. It is crucial that you pace yourself and do not make the
 // mistake of losing faith.
 //
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct WorkOrderParameter
 {
     pub locked_in_period: Option<Period>,
@@ -49,10 +58,10 @@ pub struct WorkOrderParameter
     pub work_load: HashMap<Skill, Work>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StrategicResources(pub HashMap<Period, HashMap<TechnicianId, OperationalResource>>);
 
-#[derive(Clone, PartialEq, Debug, Default)]
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
 pub struct OperationalResource
 {
     pub id: TechnicianId,
@@ -60,14 +69,220 @@ pub struct OperationalResource
     pub skill_hours: HashMap<Skill, Work>,
 }
 
-impl From<&ScheduleGraph> for StrategicInstance {
-    fn from(_value: &ScheduleGraph) -> Self {
-        // TODO [ ] - You have to derive the StrategicInstance
+impl StrategicResources
+{
+    /// Hours of every `Skill` in the `Period` summed over all
+    /// `Technician`s. A `Technician` with several `Skill`s counts towards
+    /// each of them.
+    pub fn skill_capacity(&self, period: &Period) -> HashMap<Skill, Work>
+    {
+        let mut skill_capacity: HashMap<Skill, Work> = HashMap::new();
+        for operational_resource in self.0.get(period).into_iter().flat_map(HashMap::values) {
+            for (skill, hours) in &operational_resource.skill_hours {
+                *skill_capacity.entry(skill.clone()).or_insert(0.0) += hours;
+            }
+        }
+        skill_capacity
+    }
+
+    /// The capacity of every `Technician` in every `Period` where the
+    /// available hours of each day are limited by the `WorkingHoursRule`.
+    pub fn from_schedule_graph(schedule_graph: &ScheduleGraph, working_hours_rule: &WorkingHoursRule) -> Self
+    {
+        let strategic_resources = schedule_graph
+            .derive_technician_capacity(working_hours_rule)
+            .into_iter()
+            .map(|(period, technician_capacity)| {
+                let operational_resources = technician_capacity
+                    .into_iter()
+                    .map(|(technician_id, capacity)| {
+                        let operational_resource = OperationalResource {
+                            id: technician_id,
+                            total_hours: capacity.total_hours,
+                            skill_hours: capacity.skill_hours,
+                        };
+                        (technician_id, operational_resource)
+                    })
+                    .collect();
+                (period, operational_resources)
+            })
+            .collect();
+
+        Self(strategic_resources)
+    }
+}
+
+impl StrategicInstance
+{
+    pub fn new(schedule_graph: &ScheduleGraph, strategic_options: &StrategicOptions) -> Self
+    {
+        let strategic_periods = schedule_graph.periods();
+
+        let strategic_work_order_parameters = schedule_graph
+            .work_order_numbers()
+            .into_iter()
+            .filter_map(|work_order_number| {
+                Self::work_order_parameter(schedule_graph, strategic_options, &work_order_number)
+                    .map(|work_order_parameter| (work_order_number, work_order_parameter))
+            })
+            .collect();
+
+        // The strategic planning may only change the draft `Period`s
+        let mut period_locks = schedule_graph.locked_periods();
+        period_locks.extend(strategic_periods.iter().filter(|period| Self::is_period_closed(schedule_graph, period)));
+
+        let strategic_capacity = StrategicResources::from_schedule_graph(schedule_graph, strategic_options.working_hours_rule());
+
         Self {
-            strategic_work_order_parameters: todo!(),
-            strategic_capacity: todo!(),
-            period_locks: todo!(),
-            strategic_periods: todo!(),
+            strategic_work_order_parameters,
+            strategic_capacity,
+            period_locks,
+            strategic_periods,
+        }
+    }
+
+    /// Brings the `StrategicInstance` up to date with the `GraphChange`s.
+    /// Only the changed `WorkOrder`s, the capacity of the changed
+    /// `Technician`s and the locks of the `Period`s with changed days are
+    /// derived again. When the horizon changed or the changes were dropped
+    /// from the change log the whole `StrategicInstance` is derived again.
+    ///
+    /// The `PeriodState`s are read from the clock of the `ScheduleGraph` on
+    /// every update. A `Period` never leaves the `period_locks` because of
+    /// its `PeriodState`, as the clock only moves forward.
+    pub fn update(&mut self, schedule_graph: &ScheduleGraph, strategic_options: &StrategicOptions, graph_changes: &[GraphChange])
+    {
+        if graph_changes
+            .iter()
+            .any(|graph_change| matches!(graph_change, GraphChange::Period(_) | GraphChange::ResyncRequired))
+        {
+            *self = Self::new(schedule_graph, strategic_options);
+            return;
         }
+
+        let mut work_order_numbers = BTreeSet::new();
+        let mut technician_ids = BTreeSet::new();
+        let mut periods = BTreeSet::new();
+        for graph_change in graph_changes {
+            match graph_change {
+                GraphChange::WorkOrder(work_order_number) | GraphChange::Activity(work_order_number, _) => {
+                    work_order_numbers.insert(*work_order_number);
+                }
+                GraphChange::Technician(technician_id) => {
+                    technician_ids.insert(*technician_id);
+                }
+                GraphChange::Day(day) => periods.extend(self.strategic_periods.iter().filter(|period| period.contains(*day))),
+                GraphChange::Period(_) | GraphChange::ResyncRequired => (),
+            }
+        }
+
+        for work_order_number in work_order_numbers {
+            match Self::work_order_parameter(schedule_graph, strategic_options, &work_order_number) {
+                Some(work_order_parameter) => self.strategic_work_order_parameters.insert(work_order_number, work_order_parameter),
+                None => self.strategic_work_order_parameters.remove(&work_order_number),
+            };
+        }
+
+        for technician_id in technician_ids {
+            for operational_resources in self.strategic_capacity.0.values_mut() {
+                operational_resources.remove(&technician_id);
+            }
+            let technician_capacity = schedule_graph
+                .technician_capacity(&technician_id, strategic_options.working_hours_rule())
+                .expect("The TechnicianId comes from the graph");
+            for (period, capacity) in technician_capacity {
+                let operational_resource = OperationalResource {
+                    id: technician_id,
+                    total_hours: capacity.total_hours,
+                    skill_hours: capacity.skill_hours,
+                };
+                self.strategic_capacity
+                    .0
+                    .entry(period)
+                    .or_default()
+                    .insert(technician_id, operational_resource);
+            }
+        }
+        self.strategic_capacity
+            .0
+            .retain(|_, operational_resources| !operational_resources.is_empty());
+
+        for period in periods {
+            if schedule_graph.is_period_locked(period).expect("The Period comes from the graph") {
+                self.period_locks.insert(*period);
+            } else {
+                self.period_locks.remove(period);
+            }
+        }
+        self.period_locks.extend(
+            self.strategic_periods
+                .iter()
+                .filter(|period| Self::is_period_closed(schedule_graph, period)),
+        );
+    }
+
+    /// A `WorkOrder` that is locked in a `Period` outside of the
+    /// `strategic_periods`, e.g. after `ScheduleGraph::roll_horizon`, can not
+    /// be planned. It is left out of every `StrategicSolution` so that it
+    /// keeps its current `Period`.
+    pub fn is_locked_outside_horizon(&self, work_order_number: &WorkOrderNumber) -> bool
+    {
+        self.strategic_work_order_parameters
+            .get(work_order_number)
+            .and_then(|work_order_parameter| work_order_parameter.locked_in_period)
+            .is_some_and(|locked_in_period| !self.strategic_periods.contains(&locked_in_period))
+    }
+
+    /// `None` for completed `WorkOrder`s as they are not planned anymore and
+    /// when the horizon has no `Period` to plan them in.
+    fn work_order_parameter(
+        schedule_graph: &ScheduleGraph,
+        strategic_options: &StrategicOptions,
+        work_order_number: &WorkOrderNumber,
+    ) -> Option<WorkOrderParameter>
+    {
+        if schedule_graph.is_work_order_completed(work_order_number) {
+            return None;
+        }
+        let latest_period = match schedule_graph.work_order_latest_period(work_order_number) {
+            Ok(latest_period) => latest_period,
+            Err(ScheduleGraphErrors::PeriodMissing) => return None,
+            Err(error) => panic!("The WorkOrderNumber comes from the graph: {error:?}"),
+        };
+
+        Some(WorkOrderParameter {
+            locked_in_period: schedule_graph
+                .work_order_locked_period(work_order_number)
+                .expect("The WorkOrderNumber comes from the graph"),
+            excluded_periods: schedule_graph
+                .work_order_exclusions(work_order_number)
+                .expect("The WorkOrderNumber comes from the graph"),
+            latest_period,
+            weight: strategic_options.work_order_weight(
+                schedule_graph
+                    .work_order_info(work_order_number)
+                    .expect("The WorkOrderNumber comes from the graph"),
+            ),
+            work_load: schedule_graph
+                .work_order_work_load(work_order_number)
+                .expect("The WorkOrderNumber comes from the graph"),
+        })
+    }
+
+    /// The strategic planning may only change the draft `Period`s
+    fn is_period_closed(schedule_graph: &ScheduleGraph, period: &Period) -> bool
+    {
+        matches!(
+            schedule_graph.period_state(period).expect("The Period comes from the graph"),
+            PeriodState::Previous | PeriodState::Frozen
+        )
+    }
+}
+
+impl From<&ScheduleGraph> for StrategicInstance
+{
+    fn from(schedule_graph: &ScheduleGraph) -> Self
+    {
+        Self::new(schedule_graph, &StrategicOptions::default())
     }
 }
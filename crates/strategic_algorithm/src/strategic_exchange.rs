@@ -0,0 +1,290 @@
+use scheduling_environment::Period;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::strategic_solution::StrategicSolution;
+
+/// Reasons why an imported `StrategicSolution` does not fit its
+/// `StrategicInstance`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolutionViolation
+{
+    WorkOrderUnknown(WorkOrderNumber),
+    /// The `Period` is not one of the `strategic_periods`.
+    PeriodUnknown
+    {
+        work_order_number: WorkOrderNumber,
+        period: Period,
+    },
+    PeriodExcluded
+    {
+        work_order_number: WorkOrderNumber,
+        period: Period,
+    },
+    /// The `WorkOrder` is moved out of its `locked_in_period`.
+    WorkOrderLocked
+    {
+        work_order_number: WorkOrderNumber,
+        locked_in_period: Period,
+    },
+    /// The `WorkOrder` is scheduled in one of the `period_locks`.
+    PeriodLocked
+    {
+        work_order_number: WorkOrderNumber,
+        period: Period,
+    },
+}
+
+#[derive(Debug)]
+pub enum StrategicImportError
+{
+    Json(serde_json::Error),
+    /// Every `SolutionViolation` sorted by `WorkOrderNumber`
+    Invalid(Vec<SolutionViolation>),
+}
+
+/// JSON exchange with optimizers outside of the crate.
+impl StrategicInstance
+{
+    /// Every `Period` is written as its start date and every `Work` in hours.
+    /// The maps are keyed by `WorkOrderNumber`, `Period`, `TechnicianId` and
+    /// `Skill`.
+    ///
+    /// ```json
+    /// {
+    ///   "strategic_work_order_parameters": {
+    ///     "1122334455": {
+    ///       "locked_in_period": null,
+    ///       "excluded_periods": ["2025-01-27"],
+    ///       "latest_period": "2025-02-10",
+    ///       "weight": 32,
+    ///       "work_load": { "MtnMech": 16.0 }
+    ///     }
+    ///   },
+    ///   "strategic_capacity": {
+    ///     "2025-01-13": {
+    ///       "1001": { "id": 1001, "total_hours": 40.0, "skill_hours": { "MtnMech": 40.0 } }
+    ///     }
+    ///   },
+    ///   "period_locks": ["2025-01-13"],
+    ///   "strategic_periods": ["2025-01-13", "2025-01-27", "2025-02-10"]
+    /// }
+    /// ```
+    pub fn to_json(&self) -> serde_json::Result<String>
+    {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self>
+    {
+        serde_json::from_str(json)
+    }
+}
+
+impl StrategicSolution
+{
+    /// The `Period` of every `WorkOrder` written as its start date. `null`
+    /// leaves the `WorkOrder` unscheduled and a `WorkOrder` that is missing
+    /// keeps its current `Period` when the `StrategicSolution` is applied.
+    ///
+    /// ```json
+    /// {
+    ///   "work_order_periods": {
+    ///     "1122334455": "2025-01-27",
+    ///     "1122334456": null
+    ///   }
+    /// }
+    /// ```
+    pub fn to_json(&self) -> serde_json::Result<String>
+    {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Reads a `StrategicSolution` in the format of
+    /// `StrategicSolution::to_json` and validates it against the
+    /// `StrategicInstance` that it was solved for.
+    pub fn import(json: &str, strategic_instance: &StrategicInstance) -> Result<Self, StrategicImportError>
+    {
+        let strategic_solution: Self = serde_json::from_str(json).map_err(StrategicImportError::Json)?;
+
+        let solution_violations = strategic_solution.validate(strategic_instance);
+        if !solution_violations.is_empty() {
+            return Err(StrategicImportError::Invalid(solution_violations));
+        }
+        Ok(strategic_solution)
+    }
+
+    /// Checks every `WorkOrder` of the `StrategicSolution` against the
+    /// `WorkOrderParameter`s and the `period_locks` of the
+    /// `StrategicInstance`. Only a `WorkOrder` with a `locked_in_period` may be
+    /// scheduled in a locked `Period`. The capacity is not checked as an
+    /// overload is only a cost.
+    pub fn validate(&self, strategic_instance: &StrategicInstance) -> Vec<SolutionViolation>
+    {
+        let mut work_order_numbers = self.work_order_periods.keys().copied().collect::<Vec<_>>();
+        work_order_numbers.sort();
+
+        let mut solution_violations = vec![];
+        for work_order_number in work_order_numbers {
+            let Some(work_order_parameter) = strategic_instance.strategic_work_order_parameters.get(&work_order_number) else {
+                solution_violations.push(SolutionViolation::WorkOrderUnknown(work_order_number));
+                continue;
+            };
+            let period = self.period(&work_order_number);
+
+            if let Some(locked_in_period) = work_order_parameter.locked_in_period
+                && period != Some(locked_in_period)
+            {
+                solution_violations.push(SolutionViolation::WorkOrderLocked {
+                    work_order_number,
+                    locked_in_period,
+                });
+            }
+            let Some(period) = period else {
+                continue;
+            };
+            if !strategic_instance.strategic_periods.contains(&period) {
+                solution_violations.push(SolutionViolation::PeriodUnknown { work_order_number, period });
+            } else if work_order_parameter.excluded_periods.contains(&period) {
+                solution_violations.push(SolutionViolation::PeriodExcluded { work_order_number, period });
+            } else if strategic_instance.period_locks.contains(&period) && work_order_parameter.locked_in_period != Some(period) {
+                solution_violations.push(SolutionViolation::PeriodLocked { work_order_number, period });
+            }
+        }
+        solution_violations
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+
+    use super::SolutionViolation;
+    use super::StrategicImportError;
+    use crate::OperationalResource;
+    use crate::StrategicInstance;
+    use crate::StrategicResources;
+    use crate::WorkOrderParameter;
+    use crate::strategic_solution::StrategicSolution;
+
+    fn period(day: u32) -> Period
+    {
+        Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap())
+    }
+
+    fn february_period(day: u32) -> Period
+    {
+        Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, day).unwrap())
+    }
+
+    fn strategic_instance() -> StrategicInstance
+    {
+        let work_order_parameter = |locked_in_period, excluded_periods: Vec<Period>| WorkOrderParameter {
+            locked_in_period,
+            excluded_periods: excluded_periods.into_iter().collect(),
+            latest_period: period(27),
+            weight: 32,
+            work_load: HashMap::from([(Skill::new("MtnMech"), 16.0)]),
+        };
+        let operational_resource = OperationalResource {
+            id: 1001,
+            total_hours: 40.0,
+            skill_hours: HashMap::from([(Skill::new("MtnMech"), 40.0)]),
+        };
+
+        StrategicInstance {
+            strategic_work_order_parameters: HashMap::from([
+                (1122334455, work_order_parameter(None, vec![period(27)])),
+                (1122334456, work_order_parameter(Some(period(13)), vec![])),
+            ]),
+            strategic_capacity: StrategicResources(HashMap::from([(period(13), HashMap::from([(1001, operational_resource)]))])),
+            period_locks: HashSet::from([period(13)]),
+            strategic_periods: vec![period(13), period(27), february_period(10)],
+        }
+    }
+
+    #[test]
+    fn test_strategic_instance_json()
+    {
+        let strategic_instance = strategic_instance();
+
+        let json = strategic_instance.to_json().unwrap();
+        assert_eq!(StrategicInstance::from_json(&json).unwrap(), strategic_instance);
+
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["strategic_periods"], serde_json::json!(["2025-01-13", "2025-01-27", "2025-02-10"]));
+        assert_eq!(
+            value["strategic_work_order_parameters"]["1122334455"]["excluded_periods"],
+            serde_json::json!(["2025-01-27"])
+        );
+        assert_eq!(value["strategic_capacity"]["2025-01-13"]["1001"]["skill_hours"]["MtnMech"], 40.0);
+    }
+
+    #[test]
+    fn test_import_strategic_solution()
+    {
+        let strategic_instance = strategic_instance();
+
+        let json = r#"{ "work_order_periods": { "1122334455": "2025-02-10", "1122334456": "2025-01-13" } }"#;
+        let strategic_solution = StrategicSolution::import(json, &strategic_instance).unwrap();
+        assert_eq!(strategic_solution.period(&1122334455), Some(february_period(10)));
+        assert_eq!(
+            StrategicSolution::import(&strategic_solution.to_json().unwrap(), &strategic_instance).unwrap(),
+            strategic_solution
+        );
+
+        let json = r#"{ "work_order_periods": { "1122334455": "2025-01-27", "1122334456": null, "1122334457": null } }"#;
+        let Err(StrategicImportError::Invalid(solution_violations)) = StrategicSolution::import(json, &strategic_instance) else {
+            panic!("The StrategicSolution breaks the StrategicInstance")
+        };
+        assert_eq!(
+            solution_violations,
+            vec![
+                SolutionViolation::PeriodExcluded {
+                    work_order_number: 1122334455,
+                    period: period(27),
+                },
+                SolutionViolation::WorkOrderLocked {
+                    work_order_number: 1122334456,
+                    locked_in_period: period(13),
+                },
+                SolutionViolation::WorkOrderUnknown(1122334457),
+            ]
+        );
+
+        let json = r#"{ "work_order_periods": { "1122334455": "2025-01-13" } }"#;
+        let Err(StrategicImportError::Invalid(solution_violations)) = StrategicSolution::import(json, &strategic_instance) else {
+            panic!("The Period is locked in the StrategicInstance")
+        };
+        assert_eq!(
+            solution_violations,
+            vec![SolutionViolation::PeriodLocked {
+                work_order_number: 1122334455,
+                period: period(13),
+            }]
+        );
+
+        let json = r#"{ "work_order_periods": { "1122334455": "2025-02-24" } }"#;
+        let Err(StrategicImportError::Invalid(solution_violations)) = StrategicSolution::import(json, &strategic_instance) else {
+            panic!("The Period is not in the StrategicInstance")
+        };
+        assert_eq!(
+            solution_violations,
+            vec![SolutionViolation::PeriodUnknown {
+                work_order_number: 1122334455,
+                period: february_period(24),
+            }]
+        );
+
+        assert!(matches!(
+            StrategicSolution::import("{ \"work_order_periods\": 5 }", &strategic_instance),
+            Err(StrategicImportError::Json(_))
+        ));
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::strategic_solution::StrategicSolution;
+
+/// Number of terms on every line of the LP file. CPLEX limits the length of
+/// a line.
+const TERMS_PER_LINE: usize = 8;
+
+/// Name of the binary variable that schedules the `WorkOrder` in the
+/// `Period`, e.g. `x_1122334455_20250113`.
+pub fn lp_variable_name(work_order_number: WorkOrderNumber, period: &Period) -> String
+{
+    format!("x_{}_{}", work_order_number, period.start_date().format("%Y%m%d"))
+}
+
+/// Export of the exact model that the `StrategicSolver` works on.
+impl StrategicInstance
+{
+    /// Writes the period assignment problem as a CPLEX LP file.
+    ///
+    /// There is a binary variable for every `WorkOrder` and every `Period`,
+    /// named by `lp_variable_name`. The model is:
+    /// * Maximize the weight of every scheduled `WorkOrder` times the number of
+    ///   `Period`s from its `Period` to the end of the horizon.
+    /// * `assign_*`: every `WorkOrder` is scheduled in at most one `Period`.
+    /// * `capacity_*`: the `Work` of every `Skill` in a `Period` fits inside of
+    ///   its capacity. The `Skill`s are numbered in sorted order and named in a
+    ///   comment.
+    /// * The variables of the `excluded_periods`, the `Period`s after the
+    ///   `latest_period` and the `period_locks` are fixed to zero in the
+    ///   bounds.
+    /// * A `WorkOrder` with a `locked_in_period` is fixed to it. Its `Work` is
+    ///   always subtracted from the capacity, so the capacity is raised to the
+    ///   locked `Work` when that alone does not fit. A `WorkOrder` locked
+    ///   outside of the horizon has no variables.
+    pub fn to_lp(&self) -> String
+    {
+        let mut periods = self.strategic_periods.clone();
+        periods.sort();
+        let mut work_order_numbers = self
+            .strategic_work_order_parameters
+            .keys()
+            .copied()
+            .filter(|work_order_number| !self.is_locked_outside_horizon(work_order_number))
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        let skills = self
+            .strategic_work_order_parameters
+            .values()
+            .flat_map(|work_order_parameter| work_order_parameter.work_load.keys())
+            .chain(self.strategic_capacity.0.values().flat_map(|operational_resources| {
+                operational_resources
+                    .values()
+                    .flat_map(|operational_resource| operational_resource.skill_hours.keys())
+            }))
+            .collect::<BTreeSet<_>>();
+
+        let mut objective = vec![];
+        let mut assign_constraints = vec![];
+        let mut fixed_variables = vec![];
+        let mut period_skill_work: BTreeMap<(&Period, &Skill), Vec<(Work, String)>> = BTreeMap::new();
+        let mut locked_work: HashMap<(&Period, &Skill), Work> = HashMap::new();
+        for work_order_number in &work_order_numbers {
+            let work_order_parameter = &self.strategic_work_order_parameters[work_order_number];
+
+            let mut assign_terms = vec![];
+            for (period_index, period) in periods.iter().enumerate() {
+                let variable = lp_variable_name(*work_order_number, period);
+                objective.push((
+                    work_order_parameter.weight as f64 * (periods.len() - period_index) as f64,
+                    variable.clone(),
+                ));
+                assign_terms.push((1.0, variable.clone()));
+
+                let is_allowed = match work_order_parameter.locked_in_period {
+                    Some(locked_in_period) => locked_in_period == *period,
+                    None => {
+                        !work_order_parameter.excluded_periods.contains(period)
+                            && *period <= work_order_parameter.latest_period
+                            && !self.period_locks.contains(period)
+                    }
+                };
+                if !is_allowed {
+                    fixed_variables.push(format!(" {variable} = 0"));
+                } else if work_order_parameter.locked_in_period.is_some() {
+                    fixed_variables.push(format!(" {variable} = 1"));
+                }
+
+                for (skill, work) in &work_order_parameter.work_load {
+                    if *work == 0.0 {
+                        continue;
+                    }
+                    period_skill_work.entry((period, skill)).or_default().push((*work, variable.clone()));
+                    if work_order_parameter.locked_in_period == Some(*period) {
+                        *locked_work.entry((period, skill)).or_insert(0.0) += work;
+                    }
+                }
+            }
+            assign_constraints.push((format!("assign_{work_order_number}"), assign_terms));
+        }
+
+        let mut lp = String::new();
+        writeln!(lp, "\\ Strategic period assignment").unwrap();
+        for (skill_index, skill) in skills.iter().enumerate() {
+            writeln!(lp, "\\ s{skill_index}: {skill:?}").unwrap();
+        }
+        writeln!(lp, "Maximize").unwrap();
+        write_terms(&mut lp, "obj", &objective);
+        writeln!(lp).unwrap();
+        writeln!(lp, "Subject To").unwrap();
+        for (name, terms) in &assign_constraints {
+            write_terms(&mut lp, name, terms);
+            writeln!(lp, " <= 1").unwrap();
+        }
+        for ((period, skill), terms) in &period_skill_work {
+            let skill_index = skills.iter().position(|other| other == skill).expect("Every Skill is numbered");
+            let capacity = self
+                .strategic_capacity
+                .skill_capacity(period)
+                .get(*skill)
+                .copied()
+                .unwrap_or(0.0)
+                .max(locked_work.get(&(*period, *skill)).copied().unwrap_or(0.0));
+
+            write_terms(
+                &mut lp,
+                &format!("capacity_{}_s{}", period.start_date().format("%Y%m%d"), skill_index),
+                terms,
+            );
+            writeln!(lp, " <= {capacity}").unwrap();
+        }
+        writeln!(lp, "Bounds").unwrap();
+        for fixed_variable in &fixed_variables {
+            writeln!(lp, "{fixed_variable}").unwrap();
+        }
+        writeln!(lp, "Binaries").unwrap();
+        for (_, variable) in &objective {
+            writeln!(lp, " {variable}").unwrap();
+        }
+        writeln!(lp, "End").unwrap();
+        lp
+    }
+}
+
+impl StrategicSolution
+{
+    /// Reads the values of the variables of `StrategicInstance::to_lp` as
+    /// reported by a MIP solver. A `WorkOrder` without a variable above one
+    /// half is unscheduled. A `WorkOrder` locked outside of the horizon is
+    /// left out.
+    pub fn from_lp_variables(strategic_instance: &StrategicInstance, variables: &HashMap<String, f64>) -> Self
+    {
+        let mut strategic_solution = StrategicSolution::default();
+        for work_order_number in strategic_instance.strategic_work_order_parameters.keys() {
+            if strategic_instance.is_locked_outside_horizon(work_order_number) {
+                continue;
+            }
+            let period = strategic_instance.strategic_periods.iter().copied().find(|period| {
+                variables
+                    .get(&lp_variable_name(*work_order_number, period))
+                    .is_some_and(|value| *value > 0.5)
+            });
+            strategic_solution.schedule(*work_order_number, period);
+        }
+        strategic_solution
+    }
+}
+
+/// Writes `name: c1 x1 + c2 x2 ...` without a line break at the end.
+fn write_terms(lp: &mut String, name: &str, terms: &[(f64, String)])
+{
+    write!(lp, " {name}:").unwrap();
+    if terms.is_empty() {
+        write!(lp, " 0").unwrap();
+    }
+    for (term_index, (coefficient, variable)) in terms.iter().enumerate() {
+        if term_index > 0 && term_index % TERMS_PER_LINE == 0 {
+            write!(lp, "\n   ").unwrap();
+        }
+        let sign = if term_index == 0 { "" } else { "+ " };
+        write!(lp, " {sign}{coefficient} {variable}").unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+
+    use super::lp_variable_name;
+    use crate::OperationalResource;
+    use crate::StrategicInstance;
+    use crate::StrategicResources;
+    use crate::WorkOrderParameter;
+    use crate::strategic_solution::StrategicSolution;
+
+    fn period(day: u32) -> Period
+    {
+        Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap())
+    }
+
+    fn work_order_parameter(mechanic_work: f64) -> WorkOrderParameter
+    {
+        WorkOrderParameter {
+            locked_in_period: None,
+            excluded_periods: HashSet::new(),
+            latest_period: period(27),
+            weight: 2,
+            work_load: HashMap::from([(Skill::new("MtnMech"), mechanic_work)]),
+        }
+    }
+
+    #[test]
+    fn test_strategic_instance_to_lp()
+    {
+        let mut excluded = work_order_parameter(16.0);
+        excluded.excluded_periods = [period(13)].into_iter().collect();
+        let mut locked = work_order_parameter(50.0);
+        locked.locked_in_period = Some(period(27));
+
+        let operational_resource = OperationalResource {
+            id: 1001,
+            total_hours: 40.0,
+            skill_hours: HashMap::from([(Skill::new("MtnMech"), 40.0)]),
+        };
+        let strategic_instance = StrategicInstance {
+            strategic_work_order_parameters: HashMap::from([(1122334455, excluded), (1122334456, locked)]),
+            strategic_capacity: StrategicResources(HashMap::from([
+                (period(13), HashMap::from([(1001, operational_resource.clone())])),
+                (period(27), HashMap::from([(1001, operational_resource)])),
+            ])),
+            period_locks: HashSet::new(),
+            strategic_periods: vec![period(27), period(13)],
+        };
+
+        let lp = strategic_instance.to_lp();
+
+        assert_eq!(
+            lp.lines().collect::<Vec<_>>(),
+            vec![
+                "\\ Strategic period assignment",
+                "\\ s0: Skill(\"MtnMech\")",
+                "Maximize",
+                " obj: 4 x_1122334455_20250113 + 2 x_1122334455_20250127 + 4 x_1122334456_20250113 + 2 x_1122334456_20250127",
+                "Subject To",
+                " assign_1122334455: 1 x_1122334455_20250113 + 1 x_1122334455_20250127 <= 1",
+                " assign_1122334456: 1 x_1122334456_20250113 + 1 x_1122334456_20250127 <= 1",
+                " capacity_20250113_s0: 16 x_1122334455_20250113 + 50 x_1122334456_20250113 <= 40",
+                " capacity_20250127_s0: 16 x_1122334455_20250127 + 50 x_1122334456_20250127 <= 50",
+                "Bounds",
+                " x_1122334455_20250113 = 0",
+                " x_1122334456_20250113 = 0",
+                " x_1122334456_20250127 = 1",
+                "Binaries",
+                " x_1122334455_20250113",
+                " x_1122334455_20250127",
+                " x_1122334456_20250113",
+                " x_1122334456_20250127",
+                "End",
+            ]
+        );
+
+        let variables = HashMap::from([
+            (lp_variable_name(1122334455, &period(13)), 0.0),
+            (lp_variable_name(1122334455, &period(27)), 1.0),
+            (lp_variable_name(1122334456, &period(27)), 1.0),
+        ]);
+        let strategic_solution = StrategicSolution::from_lp_variables(&strategic_instance, &variables);
+        assert_eq!(strategic_solution.period(&1122334455), Some(period(27)));
+        assert_eq!(strategic_solution.period(&1122334456), Some(period(27)));
+
+        // A `WorkOrder` locked outside of the horizon has no variables
+        let mut archived = work_order_parameter(8.0);
+        archived.locked_in_period = Some(period(6));
+        let mut strategic_instance = strategic_instance;
+        strategic_instance.strategic_work_order_parameters.insert(1122334457, archived);
+        assert_eq!(strategic_instance.to_lp(), lp);
+        let strategic_solution = StrategicSolution::from_lp_variables(&strategic_instance, &variables);
+        assert_eq!(strategic_solution.work_order_periods.len(), 2);
+        assert!(strategic_solution.validate(&strategic_instance).is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::Criticality;
+use scheduling_environment::work_order::OrderType;
+use scheduling_environment::work_order::Priority;
+use scheduling_environment::work_order::WorkOrderInfo;
+
+/// Configuration of how the `StrategicInstance` is derived from the
+/// `ScheduleGraph`.
+///
+/// The weight of a `WorkOrder` is the product of the weights of its
+/// `Priority`, `Criticality` and `OrderType`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategicOptions
+{
+    priority_weights: HashMap<Priority, i64>,
+    criticality_weights: HashMap<Criticality, i64>,
+    order_type_weights: HashMap<OrderType, i64>,
+    working_hours_rule: WorkingHoursRule,
+}
+
+impl StrategicOptions
+{
+    pub fn with_priority_weight(mut self, priority: Priority, weight: i64) -> Self
+    {
+        self.priority_weights.insert(priority, weight);
+        self
+    }
+
+    pub fn with_criticality_weight(mut self, criticality: Criticality, weight: i64) -> Self
+    {
+        self.criticality_weights.insert(criticality, weight);
+        self
+    }
+
+    pub fn with_order_type_weight(mut self, order_type: OrderType, weight: i64) -> Self
+    {
+        self.order_type_weights.insert(order_type, weight);
+        self
+    }
+
+    pub fn with_working_hours_rule(mut self, working_hours_rule: WorkingHoursRule) -> Self
+    {
+        self.working_hours_rule = working_hours_rule;
+        self
+    }
+
+    pub fn working_hours_rule(&self) -> &WorkingHoursRule
+    {
+        &self.working_hours_rule
+    }
+
+    pub fn work_order_weight(&self, work_order_info: &WorkOrderInfo) -> i64
+    {
+        // Every variant is inserted by `Default` and the setters can only
+        // overwrite them.
+        self.priority_weights[&work_order_info.priority]
+            * self.criticality_weights[&work_order_info.criticality]
+            * self.order_type_weights[&work_order_info.order_type]
+    }
+}
+
+impl Default for StrategicOptions
+{
+    fn default() -> Self
+    {
+        Self {
+            priority_weights: HashMap::from([(Priority::Emergency, 8), (Priority::High, 4), (Priority::Medium, 2), (Priority::Low, 1)]),
+            criticality_weights: HashMap::from([(Criticality::High, 3), (Criticality::Medium, 2), (Criticality::Low, 1)]),
+            order_type_weights: HashMap::from([(OrderType::Preventive, 2), (OrderType::Corrective, 2), (OrderType::Modification, 1)]),
+            working_hours_rule: WorkingHoursRule::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use scheduling_environment::work_order::Criticality;
+    use scheduling_environment::work_order::OrderType;
+    use scheduling_environment::work_order::Priority;
+    use scheduling_environment::work_order::WorkOrderInfo;
+
+    use super::StrategicOptions;
+
+    #[test]
+    fn test_work_order_weight()
+    {
+        let strategic_options = StrategicOptions::default();
+
+        assert_eq!(strategic_options.work_order_weight(&WorkOrderInfo::default()), 2);
+        assert_eq!(
+            strategic_options.work_order_weight(&WorkOrderInfo::new(Priority::Emergency, Criticality::High, OrderType::Preventive)),
+            48
+        );
+
+        let strategic_options = strategic_options
+            .with_priority_weight(Priority::Low, 0)
+            .with_order_type_weight(OrderType::Modification, 5);
+        assert_eq!(strategic_options.work_order_weight(&WorkOrderInfo::default()), 0);
+        assert_eq!(
+            strategic_options.work_order_weight(&WorkOrderInfo::new(Priority::High, Criticality::Medium, OrderType::Modification)),
+            40
+        );
+    }
+}
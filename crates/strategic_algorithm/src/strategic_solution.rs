@@ -0,0 +1,368 @@
+use std::collections::HashMap;
+
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
+use scheduling_environment::PeriodState;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::StrategicInstance;
+use crate::strategic_exchange::SolutionViolation;
+
+/// Cost of every hour of `Work` that exceeds the capacity of a `Skill` in a
+/// `Period`.
+const OVERLOAD_PENALTY_PER_HOUR: f64 = 100.0;
+
+/// Cost of every `SolutionViolation`. It outweighs the other components of
+/// any realistic horizon so that an infeasible plan never beats a feasible
+/// one.
+const VIOLATION_PENALTY: f64 = 1_000_000_000.0;
+
+/// The `Period` that every `WorkOrder` of a `StrategicInstance` is scheduled
+/// in. `None` means that the `WorkOrder` is left unscheduled.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StrategicSolution
+{
+    pub work_order_periods: HashMap<WorkOrderNumber, Option<Period>>,
+}
+
+impl StrategicSolution
+{
+    pub fn schedule(&mut self, work_order_number: WorkOrderNumber, period: Option<Period>)
+    {
+        self.work_order_periods.insert(work_order_number, period);
+    }
+
+    pub fn period(&self, work_order_number: &WorkOrderNumber) -> Option<Period>
+    {
+        self.work_order_periods.get(work_order_number).copied().flatten()
+    }
+
+    pub fn scheduled_work_orders(&self, period: &Period) -> Vec<WorkOrderNumber>
+    {
+        let mut work_order_numbers = self
+            .work_order_periods
+            .iter()
+            .filter(|(_, scheduled_period)| scheduled_period.as_ref() == Some(period))
+            .map(|(work_order_number, _)| *work_order_number)
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        work_order_numbers
+    }
+
+    pub fn unscheduled_work_orders(&self) -> Vec<WorkOrderNumber>
+    {
+        let mut work_order_numbers = self
+            .work_order_periods
+            .iter()
+            .filter(|(_, scheduled_period)| scheduled_period.is_none())
+            .map(|(work_order_number, _)| *work_order_number)
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+        work_order_numbers
+    }
+
+    /// Scores the `StrategicSolution` against the `StrategicInstance`. A
+    /// `WorkOrder` of the `StrategicInstance` that is missing from the
+    /// `StrategicSolution` counts as unscheduled, unless it is locked outside
+    /// of the horizon. Every hard constraint that the `StrategicSolution`
+    /// breaks is reported as found by `StrategicSolution::validate`.
+    pub fn evaluate(&self, strategic_instance: &StrategicInstance) -> StrategicObjective
+    {
+        let mut periods = strategic_instance.strategic_periods.clone();
+        periods.sort();
+
+        let mut strategic_objective = StrategicObjective {
+            solution_violations: self.validate(strategic_instance),
+            ..StrategicObjective::default()
+        };
+        let mut period_work: HashMap<Period, HashMap<Skill, Work>> = HashMap::new();
+        for (work_order_number, work_order_parameter) in &strategic_instance.strategic_work_order_parameters {
+            if strategic_instance.is_locked_outside_horizon(work_order_number) {
+                continue;
+            }
+            let Some(period) = self.period(work_order_number) else {
+                strategic_objective.unscheduled_penalty += work_order_parameter.weight * periods.len() as i64;
+                continue;
+            };
+
+            // A `Period` outside of the horizon is a `SolutionViolation` and earns no
+            // reward
+            if let Some(period_index) = periods.iter().position(|other| *other == period) {
+                strategic_objective.scheduling_reward += work_order_parameter.weight * (periods.len() - period_index) as i64;
+            }
+
+            let periods_late = periods
+                .iter()
+                .filter(|other| work_order_parameter.latest_period < **other && **other <= period)
+                .count();
+            strategic_objective.weighted_tardiness += work_order_parameter.weight * periods_late as i64;
+
+            for (skill, work) in &work_order_parameter.work_load {
+                *period_work.entry(period).or_default().entry(skill.clone()).or_insert(0.0) += work;
+            }
+        }
+
+        for (period, skill_work) in period_work {
+            let skill_capacity = strategic_instance.strategic_capacity.skill_capacity(&period);
+            for (skill, work) in skill_work {
+                let overload = work - skill_capacity.get(&skill).copied().unwrap_or(0.0);
+                if overload > 0.0 {
+                    strategic_objective.capacity_overload.entry(period).or_default().insert(skill, overload);
+                }
+            }
+        }
+        strategic_objective
+    }
+
+    /// Writes the `StrategicSolution` into the `ScheduleGraph` as
+    /// assignments between `WorkOrder`s and `Period`s. Only the `WorkOrder`s
+    /// whose `Period` changed are touched and the `Technician`s assigned to a
+    /// `WorkOrder` move with it. A `WorkOrder` with a
+    /// `locked_in_period` is never moved and no `WorkOrder` is moved out of or
+    /// into one of the `period_locks` of the `StrategicInstance`. The locks
+    /// and `PeriodState`s of the `ScheduleGraph` are checked as well, as the
+    /// `StrategicInstance` may be stale.
+    ///
+    /// Every move is checked with `ScheduleGraph::check_move_work_order`
+    /// before anything is written, so nothing is written when one of the
+    /// checks fails.
+    pub fn apply(
+        &self,
+        schedule_graph: &mut ScheduleGraph,
+        strategic_instance: &StrategicInstance,
+    ) -> Result<AppliedStrategicSolution, ScheduleGraphErrors>
+    {
+        let periods = schedule_graph.periods();
+
+        let mut work_order_numbers = self.work_order_periods.keys().copied().collect::<Vec<_>>();
+        work_order_numbers.sort();
+
+        let mut applied_strategic_solution = AppliedStrategicSolution::default();
+        for work_order_number in work_order_numbers {
+            let from = schedule_graph.work_order_assigned_period(&work_order_number)?;
+            let to = self.period(&work_order_number);
+            if let Some(period) = to
+                && !periods.contains(&period)
+            {
+                return Err(ScheduleGraphErrors::PeriodMissing);
+            }
+            if from == to {
+                continue;
+            }
+
+            let is_locked = |period: Option<Period>| -> Result<bool, ScheduleGraphErrors> {
+                let Some(period) = period else {
+                    return Ok(false);
+                };
+                Ok(strategic_instance.period_locks.contains(&period)
+                    || schedule_graph.is_period_locked(&period)?
+                    || !matches!(schedule_graph.period_state(&period)?, PeriodState::Draft | PeriodState::Draft2))
+            };
+            let is_locked_in_period = strategic_instance
+                .strategic_work_order_parameters
+                .get(&work_order_number)
+                .is_some_and(|work_order_parameter| work_order_parameter.locked_in_period.is_some())
+                || schedule_graph.work_order_locked_period(&work_order_number)?.is_some();
+            if is_locked(from)? || is_locked(to)? || is_locked_in_period {
+                applied_strategic_solution.locked.push(work_order_number);
+                continue;
+            }
+            schedule_graph.check_move_work_order(&work_order_number, to.as_ref())?;
+            applied_strategic_solution.moved.push(WorkOrderMove { work_order_number, from, to });
+        }
+
+        for work_order_move in &applied_strategic_solution.moved {
+            schedule_graph
+                .move_work_order(&work_order_move.work_order_number, work_order_move.to.as_ref())
+                .expect("Every move was checked before anything was written");
+        }
+        Ok(applied_strategic_solution)
+    }
+}
+
+/// Report of `StrategicSolution::apply`
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AppliedStrategicSolution
+{
+    /// The `WorkOrder`s that changed `Period`, sorted by `WorkOrderNumber`.
+    pub moved: Vec<WorkOrderMove>,
+    /// The `WorkOrder`s that the `StrategicSolution` wanted to move but which
+    /// are held in place by a lock.
+    pub locked: Vec<WorkOrderNumber>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkOrderMove
+{
+    pub work_order_number: WorkOrderNumber,
+    pub from: Option<Period>,
+    pub to: Option<Period>,
+}
+
+/// The components of the objective of a `StrategicSolution`. The
+/// `scheduling_reward` is the objective of the `StrategicSolver` and the LP
+/// model, every other component is a cost. A lower `total_cost` is a better
+/// plan and every `SolutionViolation` makes it infeasible.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct StrategicObjective
+{
+    /// Weight of every `WorkOrder` times the number of `Period`s that it is
+    /// scheduled after its `latest_period`.
+    pub weighted_tardiness: i64,
+    /// Hours of `Work` above the capacity of each `Skill` in each `Period`.
+    /// Only overloaded `Skill`s are present.
+    pub capacity_overload: HashMap<Period, HashMap<Skill, Work>>,
+    /// Weight of every unscheduled `WorkOrder` times the number of
+    /// `Period`s in the horizon, so leaving a `WorkOrder` out costs more than
+    /// scheduling it late.
+    pub unscheduled_penalty: i64,
+    /// Weight of every scheduled `WorkOrder` times the number of `Period`s
+    /// from its `Period` to the end of the horizon.
+    pub scheduling_reward: i64,
+    /// Every hard constraint that the `StrategicSolution` breaks.
+    pub solution_violations: Vec<SolutionViolation>,
+}
+
+impl StrategicObjective
+{
+    pub fn total_overload(&self) -> Work
+    {
+        self.capacity_overload.values().flat_map(HashMap::values).sum()
+    }
+
+    pub fn is_feasible(&self) -> bool
+    {
+        self.solution_violations.is_empty()
+    }
+
+    pub fn total_cost(&self) -> f64
+    {
+        self.weighted_tardiness as f64 + self.unscheduled_penalty as f64 + OVERLOAD_PENALTY_PER_HOUR * self.total_overload()
+            - self.scheduling_reward as f64
+            + VIOLATION_PENALTY * self.solution_violations.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+
+    use super::StrategicSolution;
+    use crate::OperationalResource;
+    use crate::StrategicInstance;
+    use crate::StrategicResources;
+    use crate::WorkOrderParameter;
+    use crate::strategic_exchange::SolutionViolation;
+
+    #[test]
+    fn test_evaluate()
+    {
+        let periods = [6, 20]
+            .into_iter()
+            .map(|day| Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap()))
+            .collect::<Vec<_>>();
+
+        let work_order_parameter = |weight, work| WorkOrderParameter {
+            locked_in_period: None,
+            excluded_periods: HashSet::new(),
+            latest_period: periods[0],
+            weight,
+            work_load: HashMap::from([(Skill::new("MtnMech"), work), (Skill::new("MtnElec"), 2.0)]),
+        };
+        let operational_resource = OperationalResource {
+            id: 1,
+            total_hours: 10.0,
+            skill_hours: HashMap::from([(Skill::new("MtnMech"), 10.0), (Skill::new("MtnElec"), 10.0)]),
+        };
+        let strategic_instance = StrategicInstance {
+            strategic_work_order_parameters: HashMap::from([
+                (1, work_order_parameter(3, 6.0)),
+                (2, work_order_parameter(2, 6.0)),
+                (3, work_order_parameter(5, 1.0)),
+            ]),
+            strategic_capacity: StrategicResources(
+                periods
+                    .iter()
+                    .map(|period| (*period, HashMap::from([(1, operational_resource.clone())])))
+                    .collect(),
+            ),
+            period_locks: HashSet::new(),
+            strategic_periods: periods.clone(),
+        };
+
+        let mut strategic_solution = StrategicSolution::default();
+        strategic_solution.schedule(1, Some(periods[1]));
+        strategic_solution.schedule(2, Some(periods[1]));
+        strategic_solution.schedule(3, None);
+
+        let strategic_objective = strategic_solution.evaluate(&strategic_instance);
+        assert_eq!(strategic_objective.weighted_tardiness, 3 + 2);
+        assert_eq!(strategic_objective.unscheduled_penalty, 5 * 2);
+        assert_eq!(strategic_objective.scheduling_reward, 3 + 2);
+        assert_eq!(
+            strategic_objective.capacity_overload,
+            HashMap::from([(periods[1], HashMap::from([(Skill::new("MtnMech"), 2.0)]))])
+        );
+        assert_eq!(strategic_objective.total_cost(), 5.0 + 10.0 + 200.0 - 5.0);
+
+        // Moving the `WorkOrder`s to the first `Period` removes the tardiness
+        // and the overload.
+        strategic_solution.schedule(1, Some(periods[0]));
+        strategic_solution.schedule(3, Some(periods[0]));
+        let better_objective = strategic_solution.evaluate(&strategic_instance);
+        assert_eq!(better_objective.weighted_tardiness, 2);
+        assert_eq!(better_objective.total_overload(), 0.0);
+        assert_eq!(better_objective.scheduling_reward, 3 * 2 + 2 + 5 * 2);
+        assert!(better_objective.total_cost() < strategic_objective.total_cost());
+        assert!(better_objective.is_feasible());
+
+        // Breaking a hard constraint makes the plan worse than every feasible
+        // plan.
+        let mut strategic_instance = strategic_instance;
+        strategic_instance.period_locks.insert(periods[0]);
+        let work_order_parameter = strategic_instance.strategic_work_order_parameters.get_mut(&2).unwrap();
+        work_order_parameter.locked_in_period = Some(periods[0]);
+        work_order_parameter.excluded_periods.insert(periods[1]);
+        let unknown_period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 3).unwrap());
+        strategic_solution.schedule(3, Some(unknown_period));
+        strategic_solution.schedule(4, Some(periods[0]));
+
+        let infeasible_objective = strategic_solution.evaluate(&strategic_instance);
+        assert_eq!(
+            infeasible_objective.solution_violations,
+            vec![
+                SolutionViolation::PeriodLocked {
+                    work_order_number: 1,
+                    period: periods[0],
+                },
+                SolutionViolation::WorkOrderLocked {
+                    work_order_number: 2,
+                    locked_in_period: periods[0],
+                },
+                SolutionViolation::PeriodExcluded {
+                    work_order_number: 2,
+                    period: periods[1],
+                },
+                SolutionViolation::PeriodUnknown {
+                    work_order_number: 3,
+                    period: unknown_period,
+                },
+                SolutionViolation::WorkOrderUnknown(4),
+            ]
+        );
+        assert!(!infeasible_objective.is_feasible());
+        assert_eq!(infeasible_objective.scheduling_reward, 3 * 2 + 2);
+        assert!(infeasible_objective.total_cost() > strategic_objective.total_cost());
+    }
+}
@@ -0,0 +1,496 @@
+use std::collections::HashMap;
+
+use scheduling_environment::Period;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+use crate::StrategicInstance;
+use crate::WorkOrderParameter;
+use crate::strategic_solution::StrategicSolution;
+
+type PeriodIndex = usize;
+type WorkOrderIndex = usize;
+
+const DEFAULT_ITERATIONS: usize = 10_000;
+
+/// Rounding errors of the summed `Work` must not make a `WorkOrder` fit or
+/// not fit.
+const WORK_TOLERANCE: Work = 1e-9;
+
+/// Assigns every `WorkOrder` of a `StrategicInstance` to a `Period`.
+///
+/// A greedy construction schedules the `WorkOrder`s with the highest
+/// weight first in the earliest `Period` where they fit. A local search
+/// then inserts, moves, swaps and replaces `WorkOrder`s for a number of
+/// iterations. The result only depends on the `StrategicInstance` and the
+/// seed.
+///
+/// The hard constraints are:
+/// * The `Work` of every `Skill` in a `Period` fits inside of the hours of the
+///   `Technician`s with that `Skill`.
+/// * A `WorkOrder` is never scheduled in its `excluded_periods`, after its
+///   `latest_period` or in one of the `period_locks`.
+/// * A `WorkOrder` with a `locked_in_period` is always scheduled there. Its
+///   `Work` is subtracted from the capacity even when it does not fit. A
+///   `WorkOrder` locked outside of the horizon is left out of the
+///   `StrategicSolution`, see `StrategicInstance::is_locked_outside_horizon`.
+///
+/// The objective rewards every scheduled `WorkOrder` with its weight times
+/// the number of `Period`s from its `Period` to the end of the horizon, as
+/// reported by `StrategicObjective::scheduling_reward`.
+#[derive(Debug)]
+pub struct StrategicSolver<'a>
+{
+    strategic_instance: &'a StrategicInstance,
+    seed: u64,
+    iterations: usize,
+}
+
+impl<'a> StrategicSolver<'a>
+{
+    pub fn new(strategic_instance: &'a StrategicInstance) -> Self
+    {
+        Self {
+            strategic_instance,
+            seed: 0,
+            iterations: DEFAULT_ITERATIONS,
+        }
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self
+    {
+        self.seed = seed;
+        self
+    }
+
+    pub fn with_iterations(mut self, iterations: usize) -> Self
+    {
+        self.iterations = iterations;
+        self
+    }
+
+    pub fn solve(&self) -> StrategicSolution
+    {
+        let mut solver_state = SolverState::new(self.strategic_instance);
+        solver_state.construct();
+
+        let mut random = SplitMix64::new(self.seed);
+        for _ in 0..self.iterations {
+            solver_state.local_search_step(&mut random);
+        }
+
+        solver_state.solution()
+    }
+}
+
+struct SolverState<'a>
+{
+    periods: Vec<Period>,
+    work_order_numbers: Vec<WorkOrderNumber>,
+    work_order_parameters: Vec<&'a WorkOrderParameter>,
+    /// Every `Period` that an unlocked `WorkOrder` may be scheduled in
+    candidate_periods: Vec<Vec<PeriodIndex>>,
+    locked: Vec<bool>,
+    assignments: Vec<Option<PeriodIndex>>,
+    remaining_capacity: Vec<HashMap<Skill, Work>>,
+}
+
+impl<'a> SolverState<'a>
+{
+    fn new(strategic_instance: &'a StrategicInstance) -> Self
+    {
+        let mut periods = strategic_instance.strategic_periods.clone();
+        periods.sort();
+
+        let remaining_capacity = periods
+            .iter()
+            .map(|period| strategic_instance.strategic_capacity.skill_capacity(period))
+            .collect();
+
+        let mut work_order_numbers = strategic_instance
+            .strategic_work_order_parameters
+            .keys()
+            .copied()
+            .filter(|work_order_number| !strategic_instance.is_locked_outside_horizon(work_order_number))
+            .collect::<Vec<_>>();
+        work_order_numbers.sort();
+
+        let work_order_parameters = work_order_numbers
+            .iter()
+            .map(|work_order_number| &strategic_instance.strategic_work_order_parameters[work_order_number])
+            .collect::<Vec<_>>();
+
+        let candidate_periods = work_order_parameters
+            .iter()
+            .map(|work_order_parameter| {
+                (0..periods.len())
+                    .filter(|&period_index| {
+                        let period = &periods[period_index];
+                        !work_order_parameter.excluded_periods.contains(period)
+                            && *period <= work_order_parameter.latest_period
+                            && !strategic_instance.period_locks.contains(period)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut solver_state = Self {
+            locked: vec![false; work_order_numbers.len()],
+            assignments: vec![None; work_order_numbers.len()],
+            periods,
+            work_order_numbers,
+            work_order_parameters,
+            candidate_periods,
+            remaining_capacity,
+        };
+
+        for work_order_index in 0..solver_state.work_order_numbers.len() {
+            let Some(locked_in_period) = solver_state.work_order_parameters[work_order_index].locked_in_period else {
+                continue;
+            };
+            let period_index = solver_state
+                .periods
+                .iter()
+                .position(|period| *period == locked_in_period)
+                .expect("A WorkOrder locked outside of the horizon is left out");
+            solver_state.locked[work_order_index] = true;
+            solver_state.assign(work_order_index, Some(period_index));
+        }
+        solver_state
+    }
+
+    /// Schedules the `WorkOrder`s in the earliest `Period` where they fit,
+    /// starting with the highest weight and, among equal weights, the most
+    /// `Work`.
+    fn construct(&mut self)
+    {
+        let mut work_order_indices = (0..self.work_order_numbers.len())
+            .filter(|&work_order_index| !self.locked[work_order_index])
+            .collect::<Vec<_>>();
+        work_order_indices.sort_by(|&a, &b| {
+            let total_work = |work_order_index: WorkOrderIndex| self.work_order_parameters[work_order_index].work_load.values().sum::<Work>();
+            self.weight(b)
+                .cmp(&self.weight(a))
+                .then(total_work(b).total_cmp(&total_work(a)))
+                .then(a.cmp(&b))
+        });
+
+        for work_order_index in work_order_indices {
+            if let Some(&period_index) = self.candidate_periods[work_order_index]
+                .iter()
+                .find(|&&period_index| self.fits(work_order_index, period_index))
+            {
+                self.assign(work_order_index, Some(period_index));
+            }
+        }
+    }
+
+    fn local_search_step(&mut self, random: &mut SplitMix64)
+    {
+        if self.work_order_numbers.is_empty() {
+            return;
+        }
+        let work_order_index = random.next_index(self.work_order_numbers.len());
+        if self.locked[work_order_index] || self.candidate_periods[work_order_index].is_empty() {
+            return;
+        }
+        let candidate_periods = &self.candidate_periods[work_order_index];
+        let period_index = candidate_periods[random.next_index(candidate_periods.len())];
+
+        match self.assignments[work_order_index] {
+            None => self.try_insert(work_order_index, period_index, random),
+            Some(current_period_index) if period_index < current_period_index => self.try_move(work_order_index, period_index),
+            Some(current_period_index) if current_period_index < period_index => self.try_swap(work_order_index, period_index, random),
+            Some(_) => (),
+        }
+    }
+
+    /// Schedules an unscheduled `WorkOrder`. When it does not fit a
+    /// `WorkOrder` with a lower weight is unscheduled to make room for it.
+    fn try_insert(&mut self, work_order_index: WorkOrderIndex, period_index: PeriodIndex, random: &mut SplitMix64)
+    {
+        if self.fits(work_order_index, period_index) {
+            self.assign(work_order_index, Some(period_index));
+            return;
+        }
+
+        let lighter_work_orders = self
+            .work_orders_in_period(period_index)
+            .into_iter()
+            .filter(|&other| self.weight(other) < self.weight(work_order_index))
+            .collect::<Vec<_>>();
+        if lighter_work_orders.is_empty() {
+            return;
+        }
+        let other = lighter_work_orders[random.next_index(lighter_work_orders.len())];
+
+        self.assign(other, None);
+        if self.fits(work_order_index, period_index) {
+            self.assign(work_order_index, Some(period_index));
+        } else {
+            self.assign(other, Some(period_index));
+        }
+    }
+
+    /// Moves a scheduled `WorkOrder` to an earlier `Period`
+    fn try_move(&mut self, work_order_index: WorkOrderIndex, period_index: PeriodIndex)
+    {
+        if self.fits(work_order_index, period_index) {
+            self.assign(work_order_index, Some(period_index));
+        }
+    }
+
+    /// Exchanges the `Period`s of a `WorkOrder` and one of the `WorkOrder`s
+    /// in a later `Period` when the objective does not get worse.
+    fn try_swap(&mut self, work_order_index: WorkOrderIndex, period_index: PeriodIndex, random: &mut SplitMix64)
+    {
+        let current_period_index = self.assignments[work_order_index].expect("Only scheduled WorkOrders are swapped");
+
+        let swappable_work_orders = self
+            .work_orders_in_period(period_index)
+            .into_iter()
+            .filter(|&other| self.candidate_periods[other].contains(&current_period_index) && self.weight(other) >= self.weight(work_order_index))
+            .collect::<Vec<_>>();
+        if swappable_work_orders.is_empty() {
+            return;
+        }
+        let other = swappable_work_orders[random.next_index(swappable_work_orders.len())];
+
+        self.assign(work_order_index, None);
+        self.assign(other, None);
+        if self.fits(work_order_index, period_index) && self.fits(other, current_period_index) {
+            self.assign(work_order_index, Some(period_index));
+            self.assign(other, Some(current_period_index));
+        } else {
+            self.assign(work_order_index, Some(current_period_index));
+            self.assign(other, Some(period_index));
+        }
+    }
+
+    fn work_orders_in_period(&self, period_index: PeriodIndex) -> Vec<WorkOrderIndex>
+    {
+        (0..self.work_order_numbers.len())
+            .filter(|&work_order_index| !self.locked[work_order_index] && self.assignments[work_order_index] == Some(period_index))
+            .collect()
+    }
+
+    fn weight(&self, work_order_index: WorkOrderIndex) -> i64
+    {
+        self.work_order_parameters[work_order_index].weight
+    }
+
+    fn fits(&self, work_order_index: WorkOrderIndex, period_index: PeriodIndex) -> bool
+    {
+        self.work_order_parameters[work_order_index]
+            .work_load
+            .iter()
+            .all(|(skill, work)| *work <= self.remaining_capacity[period_index].get(skill).copied().unwrap_or(0.0) + WORK_TOLERANCE)
+    }
+
+    fn assign(&mut self, work_order_index: WorkOrderIndex, period_index: Option<PeriodIndex>)
+    {
+        if let Some(previous_period_index) = self.assignments[work_order_index] {
+            for (skill, work) in &self.work_order_parameters[work_order_index].work_load {
+                *self.remaining_capacity[previous_period_index].entry(skill.clone()).or_insert(0.0) += work;
+            }
+        }
+        if let Some(period_index) = period_index {
+            for (skill, work) in &self.work_order_parameters[work_order_index].work_load {
+                *self.remaining_capacity[period_index].entry(skill.clone()).or_insert(0.0) -= work;
+            }
+        }
+        self.assignments[work_order_index] = period_index;
+    }
+
+    fn solution(&self) -> StrategicSolution
+    {
+        let mut strategic_solution = StrategicSolution::default();
+        for (work_order_index, work_order_number) in self.work_order_numbers.iter().enumerate() {
+            strategic_solution.schedule(
+                *work_order_number,
+                self.assignments[work_order_index].map(|period_index| self.periods[period_index]),
+            );
+        }
+        strategic_solution
+    }
+}
+
+/// Small pseudo random number generator so that a seed always gives the
+/// same `StrategicSolution` on every platform.
+struct SplitMix64(u64);
+
+impl SplitMix64
+{
+    fn new(seed: u64) -> Self
+    {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64
+    {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Index in `0..len`. `len` has to be larger than zero.
+    fn next_index(&mut self, len: usize) -> usize
+    {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::HashMap;
+    use std::collections::HashSet;
+
+    use chrono::NaiveDate;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::Work;
+
+    use super::StrategicSolver;
+    use crate::OperationalResource;
+    use crate::StrategicInstance;
+    use crate::StrategicResources;
+    use crate::WorkOrderParameter;
+
+    fn periods() -> Vec<Period>
+    {
+        [13, 27]
+            .into_iter()
+            .map(|day| Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap()))
+            .chain([Period::from_start_date(NaiveDate::from_ymd_opt(2025, 2, 10).unwrap())])
+            .collect()
+    }
+
+    fn work_order_parameter(weight: i64, mechanic_work: Work) -> WorkOrderParameter
+    {
+        WorkOrderParameter {
+            locked_in_period: None,
+            excluded_periods: HashSet::new(),
+            latest_period: *periods().last().unwrap(),
+            weight,
+            work_load: HashMap::from([(Skill::new("MtnMech"), mechanic_work)]),
+        }
+    }
+
+    /// One mechanic with 10 hours in every `Period`
+    fn strategic_instance(strategic_work_order_parameters: Vec<(u64, WorkOrderParameter)>) -> StrategicInstance
+    {
+        let strategic_capacity = periods()
+            .into_iter()
+            .map(|period| {
+                let operational_resource = OperationalResource {
+                    id: 1,
+                    total_hours: 10.0,
+                    skill_hours: HashMap::from([(Skill::new("MtnMech"), 10.0)]),
+                };
+                (period, HashMap::from([(1, operational_resource)]))
+            })
+            .collect();
+
+        StrategicInstance {
+            strategic_work_order_parameters: strategic_work_order_parameters.into_iter().collect(),
+            strategic_capacity: StrategicResources(strategic_capacity),
+            period_locks: HashSet::new(),
+            strategic_periods: periods(),
+        }
+    }
+
+    #[test]
+    fn test_solve_prefers_heavy_weights()
+    {
+        let periods = periods();
+        let strategic_instance = strategic_instance(vec![
+            (1, work_order_parameter(1, 8.0)),
+            (2, work_order_parameter(4, 8.0)),
+            (3, work_order_parameter(2, 8.0)),
+            (4, work_order_parameter(8, 8.0)),
+        ]);
+
+        let strategic_solution = StrategicSolver::new(&strategic_instance).with_seed(7).solve();
+
+        assert_eq!(strategic_solution.period(&4), Some(periods[0]));
+        assert_eq!(strategic_solution.period(&2), Some(periods[1]));
+        assert_eq!(strategic_solution.period(&3), Some(periods[2]));
+        assert_eq!(strategic_solution.unscheduled_work_orders(), vec![1]);
+    }
+
+    #[test]
+    fn test_solve_respects_constraints()
+    {
+        let periods = periods();
+
+        let mut excluded = work_order_parameter(8, 4.0);
+        excluded.excluded_periods = [periods[0]].into_iter().collect();
+        let mut latest = work_order_parameter(1, 4.0);
+        latest.latest_period = periods[0];
+        let mut locked = work_order_parameter(1, 12.0);
+        locked.locked_in_period = Some(periods[2]);
+        let mut strategic_instance = strategic_instance(vec![
+            (1, excluded),
+            (2, latest),
+            (3, locked),
+            (4, work_order_parameter(2, 8.0)),
+            (5, work_order_parameter(4, 3.0)),
+        ]);
+        strategic_instance.period_locks = [periods[1]].into_iter().collect();
+
+        let strategic_solution = StrategicSolver::new(&strategic_instance).with_seed(42).solve();
+
+        // The locked `WorkOrder` overloads its `Period` and blocks it for all others
+        assert_eq!(strategic_solution.period(&3), Some(periods[2]));
+        assert_eq!(strategic_solution.scheduled_work_orders(&periods[2]), vec![3]);
+        assert!(strategic_solution.scheduled_work_orders(&periods[1]).is_empty());
+
+        // Only the first `Period` is left: 1 is excluded from it and 4 does
+        // not fit next to 5 and 2.
+        assert_eq!(strategic_solution.period(&1), None);
+        assert_eq!(strategic_solution.scheduled_work_orders(&periods[0]), vec![2, 5]);
+        assert_eq!(strategic_solution.unscheduled_work_orders(), vec![1, 4]);
+
+        // Only the locked `WorkOrder` overloads its `Period`
+        let strategic_objective = strategic_solution.evaluate(&strategic_instance);
+        assert_eq!(strategic_objective.weighted_tardiness, 0);
+        assert_eq!(strategic_objective.scheduling_reward, 3 + 4 * 3 + 1);
+        assert_eq!(strategic_objective.capacity_overload.keys().collect::<Vec<_>>(), vec![&periods[2]]);
+    }
+
+    #[test]
+    fn test_solve_leaves_out_work_orders_locked_outside_of_the_horizon()
+    {
+        let periods = periods();
+
+        // The `Period` was archived by `ScheduleGraph::roll_horizon`
+        let mut archived = work_order_parameter(8, 4.0);
+        archived.locked_in_period = Some(Period::from_start_date(NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()));
+        let strategic_instance = strategic_instance(vec![(1, archived), (2, work_order_parameter(1, 4.0))]);
+        assert!(strategic_instance.is_locked_outside_horizon(&1));
+
+        let strategic_solution = StrategicSolver::new(&strategic_instance).with_seed(1).solve();
+        assert_eq!(strategic_solution.work_order_periods, HashMap::from([(2, Some(periods[0]))]));
+        assert!(strategic_solution.validate(&strategic_instance).is_empty());
+        assert_eq!(strategic_solution.evaluate(&strategic_instance).unscheduled_penalty, 0);
+    }
+
+    #[test]
+    fn test_solve_is_deterministic()
+    {
+        let strategic_instance = strategic_instance(
+            (1..=30)
+                .map(|number| (number, work_order_parameter((number % 4) as i64, (number % 7) as Work)))
+                .collect(),
+        );
+
+        let strategic_solver = StrategicSolver::new(&strategic_instance).with_seed(3).with_iterations(500);
+        let strategic_solution = strategic_solver.solve();
+        assert_eq!(strategic_solution, strategic_solver.solve());
+        assert_eq!(strategic_solution.evaluate(&strategic_instance).total_overload(), 0.0);
+    }
+}
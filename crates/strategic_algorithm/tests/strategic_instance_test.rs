@@ -0,0 +1,451 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::Days;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeDelta;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
+use scheduling_environment::system_clock::MockClock;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillCatalog;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrder;
+use scheduling_environment::work_order::work_hours;
+use serde::Deserialize;
+use strategic_algorithm::StrategicInstance;
+use strategic_algorithm::strategic_options::StrategicOptions;
+use strategic_algorithm::strategic_solution::StrategicSolution;
+use strategic_algorithm::strategic_solution::WorkOrderMove;
+
+/// Intermediate struct for deserializing technician data from JSON
+#[derive(Deserialize, Debug)]
+struct TechnicianData
+{
+    id: usize,
+    skills: Vec<Skill>,
+    availabilities: Vec<(NaiveDateTime, NaiveDateTime)>,
+}
+
+fn get_test_data_path(filename: &str) -> PathBuf
+{
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    PathBuf::from(manifest_dir)
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .join("tests")
+        .join(filename)
+}
+
+fn read_test_data<T: for<'de> Deserialize<'de>>(filename: &str) -> T
+{
+    let json = fs::read_to_string(get_test_data_path(filename)).unwrap_or_else(|_| panic!("Failed to read {filename}"));
+    serde_json::from_str(&json).unwrap_or_else(|_| panic!("Failed to parse {filename}"))
+}
+
+/// The clock stands before every `Period` of the test data
+fn mock_clock() -> MockClock
+{
+    MockClock::new(NaiveDate::from_ymd_opt(2024, 12, 1).unwrap().and_hms_opt(0, 0, 0).unwrap())
+}
+
+#[test]
+fn test_strategic_instance_from_schedule_graph()
+{
+    let skill_catalog: SkillCatalog = read_test_data("skills.json");
+    let period_dates: Vec<NaiveDate> = read_test_data("periods.json");
+    let work_orders: Vec<WorkOrder> = read_test_data("work_orders.json");
+    let technician_data: Vec<TechnicianData> = read_test_data("technicians.json");
+
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+    schedule_graph.add_skill_catalog(&skill_catalog);
+
+    let periods = period_dates.iter().map(|&date| Period::from_start_date(date)).collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+
+    for work_order in &work_orders {
+        schedule_graph.add_work_order(work_order).unwrap();
+    }
+
+    for tech_data in &technician_data {
+        let mut builder = Technician::builder(tech_data.id);
+        for skill in &tech_data.skills {
+            builder = builder.add_skill(skill.clone());
+        }
+        let (start, end) = tech_data.availabilities[0];
+        schedule_graph.add_technician(builder.build(), Availability::new(start, end)).unwrap();
+    }
+
+    let excluded_work_order = work_orders[0].work_order_number();
+    schedule_graph.add_exclusion(&excluded_work_order, &periods[3]).unwrap();
+    let assigned_work_order = work_orders[1].work_order_number();
+    schedule_graph
+        .add_assignment_work_order(technician_data[0].id, assigned_work_order, periods[5])
+        .unwrap();
+    let frozen_work_order = work_orders[2].work_order_number();
+    schedule_graph.freeze_work_order(&frozen_work_order, &periods[4]).unwrap();
+
+    let strategic_instance = StrategicInstance::from(&schedule_graph);
+
+    assert_eq!(strategic_instance.strategic_periods, periods);
+    assert!(strategic_instance.period_locks.is_empty());
+    assert_eq!(strategic_instance.strategic_work_order_parameters.len(), work_orders.len());
+
+    for work_order in &work_orders {
+        let work_order_parameter = &strategic_instance.strategic_work_order_parameters[&work_order.work_order_number()];
+
+        let mut expected_work_load: HashMap<Skill, Work> = HashMap::new();
+        for activity in work_order.activities() {
+            for requirement in activity.requirements() {
+                *expected_work_load.entry(requirement.skill().clone()).or_insert(0.0) +=
+                    work_hours(activity.work()) * requirement.number_of_people() as Work / activity.number_of_people() as Work;
+            }
+        }
+        assert_eq!(work_order_parameter.work_load, expected_work_load);
+        assert_eq!(work_order_parameter.latest_period, *periods.last().unwrap());
+        assert_eq!(
+            work_order_parameter.weight,
+            StrategicOptions::default().work_order_weight(work_order.work_order_info())
+        );
+    }
+
+    let excluded_parameter = &strategic_instance.strategic_work_order_parameters[&excluded_work_order];
+    assert_eq!(excluded_parameter.excluded_periods, [periods[3]].into_iter().collect());
+    // Only frozen assignments lock the `WorkOrder` into its `Period`
+    let assigned_parameter = &strategic_instance.strategic_work_order_parameters[&assigned_work_order];
+    assert_eq!(assigned_parameter.locked_in_period, None);
+    let frozen_parameter = &strategic_instance.strategic_work_order_parameters[&frozen_work_order];
+    assert_eq!(frozen_parameter.locked_in_period, Some(periods[4]));
+
+    // Every technician is available 08:00-17:00 for the first five days of
+    // the first period.
+    let first_period_capacity = &strategic_instance.strategic_capacity.0[&periods[0]];
+    assert_eq!(first_period_capacity.len(), technician_data.len());
+    for tech_data in &technician_data {
+        let operational_resource = &first_period_capacity[&tech_data.id];
+        assert_eq!(operational_resource.total_hours, 40.0);
+        for skill in &tech_data.skills {
+            assert_eq!(operational_resource.skill_hours[skill], 40.0);
+        }
+    }
+    assert_eq!(strategic_instance.strategic_capacity.0.len(), 1);
+}
+
+#[test]
+fn test_apply_strategic_solution()
+{
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+    let periods = [6, 20]
+        .into_iter()
+        .map(|day| Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap()))
+        .collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    for work_order_number in 1122334450..1122334454 {
+        let work_order = WorkOrder::new(work_order_number, periods[0].start_date(), vec![]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+    }
+    schedule_graph.add_assignment_work_order_period(&1122334450, &periods[0]).unwrap();
+    schedule_graph.add_assignment_work_order_period(&1122334451, &periods[0]).unwrap();
+    schedule_graph.add_assignment_work_order_period(&1122334452, &periods[1]).unwrap();
+
+    let mut strategic_instance = StrategicInstance::from(&schedule_graph);
+    strategic_instance.period_locks = [periods[1]].into_iter().collect();
+
+    let mut strategic_solution = StrategicSolution::default();
+    strategic_solution.schedule(1122334450, Some(periods[0]));
+    strategic_solution.schedule(1122334451, None);
+    strategic_solution.schedule(1122334452, Some(periods[0]));
+    strategic_solution.schedule(1122334453, Some(periods[0]));
+
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+
+    assert_eq!(
+        applied_strategic_solution.moved,
+        vec![
+            WorkOrderMove {
+                work_order_number: 1122334451,
+                from: Some(periods[0]),
+                to: None,
+            },
+            WorkOrderMove {
+                work_order_number: 1122334453,
+                from: None,
+                to: Some(periods[0]),
+            },
+        ]
+    );
+    assert_eq!(applied_strategic_solution.locked, vec![1122334452]);
+
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334450), Ok(Some(periods[0])));
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334451), Ok(None));
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334452), Ok(Some(periods[1])));
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334453), Ok(Some(periods[0])));
+
+    // Applying the same `StrategicSolution` again does not change anything
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert!(applied_strategic_solution.moved.is_empty());
+
+    strategic_solution.schedule(1122334459, Some(periods[0]));
+    assert_eq!(
+        strategic_solution.apply(&mut schedule_graph, &strategic_instance),
+        Err(ScheduleGraphErrors::WorkOrderMissing)
+    );
+
+    // The locks of the `ScheduleGraph` hold even when the `StrategicInstance`
+    // is stale
+    schedule_graph.freeze_work_order(&1122334453, &periods[0]).unwrap();
+    let mut strategic_solution = StrategicSolution::default();
+    strategic_solution.schedule(1122334451, Some(periods[0]));
+    strategic_solution.schedule(1122334453, None);
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert_eq!(applied_strategic_solution.locked, vec![1122334453]);
+    assert_eq!(applied_strategic_solution.moved.len(), 1);
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334453), Ok(Some(periods[0])));
+
+    schedule_graph.freeze_period(&periods[0]).unwrap();
+    strategic_solution.schedule(1122334451, None);
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert_eq!(applied_strategic_solution.locked, vec![1122334451, 1122334453]);
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334451), Ok(Some(periods[0])));
+}
+
+#[test]
+fn test_apply_strategic_solution_with_technicians()
+{
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+    let periods = [6, 20]
+        .into_iter()
+        .map(|day| Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, day).unwrap()))
+        .collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    for work_order_number in 1122334450..1122334452 {
+        let work_order = WorkOrder::new(work_order_number, periods[0].start_date(), vec![]).unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+    }
+    let day = periods[0].start_date();
+    let availability = Availability::new(day.and_hms_opt(8, 0, 0).unwrap(), day.and_hms_opt(17, 0, 0).unwrap());
+    let technician = Technician::builder(1001)
+        .add_availability(availability.start(), availability.end())
+        .unwrap()
+        .build();
+    schedule_graph.add_technician(technician, availability).unwrap();
+
+    schedule_graph.add_assignment_work_order_period(&1122334450, &periods[0]).unwrap();
+    schedule_graph.add_assignment_work_order(1001, 1122334450, periods[0]).unwrap();
+    // Only a `Technician` is assigned, the strategic plan is empty
+    schedule_graph.add_assignment_work_order(1001, 1122334451, periods[0]).unwrap();
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334451), Ok(None));
+
+    let strategic_instance = StrategicInstance::from(&schedule_graph);
+    let mut strategic_solution = StrategicSolution::default();
+    strategic_solution.schedule(1122334450, Some(periods[1]));
+    strategic_solution.schedule(1122334451, Some(periods[1]));
+
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert_eq!(
+        applied_strategic_solution.moved,
+        vec![
+            WorkOrderMove {
+                work_order_number: 1122334450,
+                from: Some(periods[0]),
+                to: Some(periods[1]),
+            },
+            WorkOrderMove {
+                work_order_number: 1122334451,
+                from: None,
+                to: Some(periods[1]),
+            },
+        ]
+    );
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334450), Ok(Some(periods[1])));
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334451), Ok(Some(periods[1])));
+    // The `Technician`s moved with their `WorkOrder`s
+    assert!(schedule_graph.validate_hierarchy().is_consistent());
+
+    let hyperedge_count = schedule_graph.hyperedge_count();
+    let applied_strategic_solution = strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert!(applied_strategic_solution.moved.is_empty());
+    assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count);
+
+    // Unscheduling the `WorkOrder` unassigns its `Technician`s
+    strategic_solution.schedule(1122334450, None);
+    strategic_solution.apply(&mut schedule_graph, &strategic_instance).unwrap();
+    assert_eq!(schedule_graph.work_order_assigned_period(&1122334450), Ok(None));
+    assert_eq!(schedule_graph.hyperedge_count(), hyperedge_count - 2);
+    assert!(schedule_graph.validate_hierarchy().is_consistent());
+}
+
+#[test]
+fn test_update_strategic_instance()
+{
+    let mock_clock = mock_clock();
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock.clone());
+    schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+    let periods = [(1, 6), (1, 20), (2, 3)]
+        .into_iter()
+        .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+        .collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    for work_order_number in 1122334450..1122334454 {
+        let work_order = WorkOrder::new(
+            work_order_number,
+            periods[0].start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+    }
+
+    let strategic_options = StrategicOptions::default();
+    let mut strategic_instance = StrategicInstance::new(&schedule_graph, &strategic_options);
+    let mut change_subscription = schedule_graph.subscribe();
+
+    let day = periods[0].start_date();
+    let availability = Availability::new(day.and_hms_opt(8, 0, 0).unwrap(), (day + Days::new(4)).and_hms_opt(17, 0, 0).unwrap());
+    let technician = Technician::builder(1001)
+        .add_availability(availability.start(), availability.end())
+        .unwrap()
+        .add_skill(Skill::new("MtnMech"))
+        .build();
+    schedule_graph.add_technician(technician, availability).unwrap();
+    schedule_graph.add_exclusion(&1122334450, &periods[1]).unwrap();
+    schedule_graph.freeze_work_order(&1122334451, &periods[2]).unwrap();
+    schedule_graph.complete_work_order(&1122334452).unwrap();
+
+    strategic_instance.update(&schedule_graph, &strategic_options, change_subscription.poll(&schedule_graph));
+    assert_eq!(strategic_instance.strategic_capacity.0[&periods[0]][&1001].total_hours, 40.0);
+    assert_eq!(
+        strategic_instance.strategic_work_order_parameters[&1122334450].excluded_periods,
+        [periods[1]].into_iter().collect()
+    );
+    assert_eq!(
+        strategic_instance.strategic_work_order_parameters[&1122334451].locked_in_period,
+        Some(periods[2])
+    );
+    assert!(!strategic_instance.strategic_work_order_parameters.contains_key(&1122334452));
+    assert_eq!(strategic_instance, StrategicInstance::new(&schedule_graph, &strategic_options));
+
+    // A locked assignment of an `Activity` locks its `Period` and the clock
+    // freezes the first `Period`
+    let start_and_finish_time = (NaiveTime::from_hms_opt(8, 0, 0).unwrap(), NaiveTime::from_hms_opt(16, 0, 0).unwrap());
+    schedule_graph
+        .add_assignment_activity(vec![1001], 1122334453, 10, vec![day], start_and_finish_time)
+        .unwrap();
+    mock_clock.set(day.and_hms_opt(0, 0, 0).unwrap());
+    schedule_graph.freeze_period(&periods[0]).unwrap();
+
+    strategic_instance.update(&schedule_graph, &strategic_options, change_subscription.poll(&schedule_graph));
+    assert_eq!(strategic_instance.period_locks, [periods[0]].into_iter().collect());
+    assert_eq!(strategic_instance, StrategicInstance::new(&schedule_graph, &strategic_options));
+
+    // Rolling the horizon derives the whole `StrategicInstance` again
+    mock_clock.set((periods[1].start_date()).and_hms_opt(0, 0, 0).unwrap());
+    schedule_graph.roll_horizon().unwrap();
+
+    strategic_instance.update(&schedule_graph, &strategic_options, change_subscription.poll(&schedule_graph));
+    assert_eq!(strategic_instance.strategic_periods[0], periods[1]);
+    assert_eq!(strategic_instance, StrategicInstance::new(&schedule_graph, &strategic_options));
+}
+
+#[test]
+fn test_strategic_instance_after_roll_horizon()
+{
+    let mock_clock = mock_clock();
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock.clone());
+    let periods = [(1, 6), (1, 20)]
+        .into_iter()
+        .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+        .collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    let work_order = WorkOrder::new(1122334450, periods[0].start_date(), vec![])
+        .unwrap()
+        .with_latest_finish(periods[0].end_date())
+        .unwrap();
+    schedule_graph.add_work_order(&work_order).unwrap();
+    let work_order = WorkOrder::new(1122334451, periods[0].start_date(), vec![])
+        .unwrap()
+        .with_latest_finish(periods[1].end_date())
+        .unwrap();
+    schedule_graph.add_work_order(&work_order).unwrap();
+
+    mock_clock.set(periods[1].start_date().and_hms_opt(0, 0, 0).unwrap());
+    let rolled_horizon = schedule_graph.roll_horizon().unwrap();
+
+    // The overdue `WorkOrder` is due in the first `Period` of the horizon
+    let strategic_instance = StrategicInstance::from(&schedule_graph);
+    assert_eq!(strategic_instance.strategic_periods, vec![periods[1], rolled_horizon.new_periods[0]]);
+    assert_eq!(strategic_instance.strategic_work_order_parameters[&1122334450].latest_period, periods[1]);
+    assert_eq!(strategic_instance.strategic_work_order_parameters[&1122334451].latest_period, periods[1]);
+}
+
+#[test]
+fn test_strategic_instance_after_freeze_period()
+{
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(mock_clock());
+    schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+    let periods = [(1, 6), (1, 20), (2, 3), (2, 17)]
+        .into_iter()
+        .map(|(month, day)| Period::from_start_date(NaiveDate::from_ymd_opt(2025, month, day).unwrap()))
+        .collect::<Vec<_>>();
+    for period in &periods {
+        schedule_graph.add_period(*period).unwrap();
+    }
+    for work_order_number in 1122334450..1122334453 {
+        let work_order = WorkOrder::new(
+            work_order_number,
+            periods[0].start_date(),
+            vec![Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8))],
+        )
+        .unwrap();
+        schedule_graph.add_work_order(&work_order).unwrap();
+    }
+
+    let strategic_options = StrategicOptions::default();
+    let mut strategic_instance = StrategicInstance::new(&schedule_graph, &strategic_options);
+    let mut change_subscription = schedule_graph.subscribe();
+
+    // Only a strategic assignment in the first `Period`, only a tactical day
+    // plan in the second one and only a frozen `WorkOrder` in the third one
+    schedule_graph.add_assignment_work_order_period(&1122334450, &periods[0]).unwrap();
+    schedule_graph
+        .add_assignment_activity_days(&1122334451, 10, vec![periods[1].start_date()])
+        .unwrap();
+    schedule_graph.freeze_work_order(&1122334452, &periods[2]).unwrap();
+    schedule_graph.freeze_period(&periods[0]).unwrap();
+    schedule_graph.freeze_period(&periods[1]).unwrap();
+
+    strategic_instance.update(&schedule_graph, &strategic_options, change_subscription.poll(&schedule_graph));
+    assert_eq!(strategic_instance.period_locks, [periods[0], periods[1]].into_iter().collect());
+    assert_eq!(
+        strategic_instance.strategic_work_order_parameters[&1122334452].locked_in_period,
+        Some(periods[2])
+    );
+    assert_eq!(strategic_instance, StrategicInstance::new(&schedule_graph, &strategic_options));
+
+    // A frozen `Period` stays locked without any assignments
+    schedule_graph.freeze_period(&periods[3]).unwrap();
+    assert!(
+        StrategicInstance::new(&schedule_graph, &strategic_options)
+            .period_locks
+            .contains(&periods[3])
+    );
+}
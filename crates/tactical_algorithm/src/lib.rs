@@ -0,0 +1,2 @@
+pub mod tactical_solution;
+pub mod tactical_solver;
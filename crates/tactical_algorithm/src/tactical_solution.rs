@@ -0,0 +1,144 @@
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use schedule_hypergraph::tactical_instance::TacticalInstance;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+
+/// The `Day`s that every `Activity` of a `TacticalInstance` is placed on. An
+/// `Activity` that is missing is left unplaced.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TacticalSolution
+{
+    pub activity_days: HashMap<(WorkOrderNumber, ActivityNumber), Vec<NaiveDate>>,
+}
+
+impl TacticalSolution
+{
+    pub fn place(&mut self, work_order_number: WorkOrderNumber, activity_number: ActivityNumber, mut days: Vec<NaiveDate>)
+    {
+        days.sort();
+        self.activity_days.insert((work_order_number, activity_number), days);
+    }
+
+    pub fn days(&self, work_order_number: WorkOrderNumber, activity_number: ActivityNumber) -> Option<&[NaiveDate]>
+    {
+        self.activity_days
+            .get(&(work_order_number, activity_number))
+            .map(Vec::as_slice)
+            .filter(|days| !days.is_empty())
+    }
+
+    /// Every `Activity` of the `TacticalInstance` without any `Day`s, sorted
+    /// by `WorkOrderNumber` and `ActivityNumber`.
+    pub fn unplaced_activities(&self, tactical_instance: &TacticalInstance) -> Vec<(WorkOrderNumber, ActivityNumber)>
+    {
+        tactical_instance
+            .tactical_work_orders
+            .iter()
+            .flat_map(|(work_order_number, tactical_work_order)| {
+                tactical_work_order
+                    .activities
+                    .keys()
+                    .map(move |activity_number| (*work_order_number, *activity_number))
+            })
+            .filter(|(work_order_number, activity_number)| self.days(*work_order_number, *activity_number).is_none())
+            .collect()
+    }
+
+    /// The `Work` of every `Skill` on every `Day`. The `Work` of an
+    /// `Activity` is spread evenly over its `Day`s.
+    pub fn day_load(&self, tactical_instance: &TacticalInstance) -> BTreeMap<NaiveDate, HashMap<Skill, Work>>
+    {
+        let mut day_load: BTreeMap<NaiveDate, HashMap<Skill, Work>> = BTreeMap::new();
+        for (work_order_number, tactical_work_order) in &tactical_instance.tactical_work_orders {
+            for (activity_number, tactical_activity) in &tactical_work_order.activities {
+                let Some(days) = self.days(*work_order_number, *activity_number) else {
+                    continue;
+                };
+                for day in days {
+                    for (skill, work) in &tactical_activity.work_load {
+                        *day_load.entry(*day).or_default().entry(skill.clone()).or_insert(0.0) += work / days.len() as Work;
+                    }
+                }
+            }
+        }
+        day_load
+    }
+
+    /// Writes the `TacticalSolution` into the `ScheduleGraph` as assignments
+    /// between `Activity`s and `Day`s. Only the `Activity`s whose `Day`s
+    /// changed are touched and an `Activity` whose `Day`s are locked is never
+    /// moved.
+    ///
+    /// Nothing is written when one of the `Activity`s or `Day`s is missing
+    /// from the `ScheduleGraph`.
+    pub fn apply(&self, schedule_graph: &mut ScheduleGraph) -> Result<AppliedTacticalSolution, ScheduleGraphErrors>
+    {
+        let periods = schedule_graph.periods();
+
+        let mut activities = self.activity_days.keys().copied().collect::<Vec<_>>();
+        activities.sort();
+
+        let mut applied_tactical_solution = AppliedTacticalSolution::default();
+        for (work_order_number, activity_number) in activities {
+            let from = schedule_graph.activity_planned_days(&work_order_number, activity_number)?;
+            let to = self.activity_days[&(work_order_number, activity_number)].clone();
+            if to.iter().any(|day| !periods.iter().any(|period| period.contains(*day))) {
+                return Err(ScheduleGraphErrors::DayMissing);
+            }
+            if from == to {
+                continue;
+            }
+
+            if schedule_graph.is_activity_days_locked(&work_order_number, activity_number)? {
+                applied_tactical_solution.locked.push((work_order_number, activity_number));
+                continue;
+            }
+            applied_tactical_solution.moved.push(ActivityMove {
+                work_order_number,
+                activity_number,
+                from,
+                to,
+            });
+        }
+
+        for activity_move in &applied_tactical_solution.moved {
+            schedule_graph.remove_assignment_activity_days(&activity_move.work_order_number, activity_move.activity_number)?;
+            if !activity_move.to.is_empty() {
+                schedule_graph.add_assignment_activity_days(
+                    &activity_move.work_order_number,
+                    activity_move.activity_number,
+                    activity_move.to.clone(),
+                )?;
+            }
+        }
+        Ok(applied_tactical_solution)
+    }
+}
+
+/// Report of `TacticalSolution::apply`
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct AppliedTacticalSolution
+{
+    /// The `Activity`s that changed `Day`s, sorted by `WorkOrderNumber` and
+    /// `ActivityNumber`.
+    pub moved: Vec<ActivityMove>,
+    /// The `Activity`s that the `TacticalSolution` wanted to move but which
+    /// are held in place by a lock.
+    pub locked: Vec<(WorkOrderNumber, ActivityNumber)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ActivityMove
+{
+    pub work_order_number: WorkOrderNumber,
+    pub activity_number: ActivityNumber,
+    pub from: Vec<NaiveDate>,
+    pub to: Vec<NaiveDate>,
+}
@@ -0,0 +1,381 @@
+use std::collections::HashMap;
+
+use chrono::NaiveDate;
+use schedule_hypergraph::tactical_instance::TacticalActivity;
+use schedule_hypergraph::tactical_instance::TacticalInstance;
+use schedule_hypergraph::tactical_instance::TacticalWorkOrder;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::ActivityNumber;
+use scheduling_environment::work_order::ActivityRelation;
+use scheduling_environment::work_order::Work;
+use scheduling_environment::work_order::WorkOrderNumber;
+use scheduling_environment::work_order::work_hours;
+
+use crate::tactical_solution::TacticalSolution;
+
+type DayIndex = usize;
+
+/// Rounding errors of the summed `Work` must not make an `Activity` fit or
+/// not fit.
+const WORK_TOLERANCE: Work = 1e-9;
+
+/// Places every `Activity` of a `TacticalInstance` on consecutive `Day`s.
+///
+/// The `WorkOrder`s are handled one at a time, ordered by their `Period`
+/// and then by their `WorkOrderNumber`. Each `Activity` is placed on the
+/// earliest `Day`s where it fits. An `Activity` that already has
+/// `assigned_days` keeps them.
+///
+/// The hard constraints are:
+/// * An `Activity` is placed on as many `Day`s as its `duration` needs when
+///   every `Day` has `hours_per_day` of working time.
+/// * The `Day`s lie inside of the `Period` of the `WorkOrder` and not before
+///   the `earliest_start` of the `Activity`.
+/// * An `Activity` starts after the last `Day` of a `FinishStart` predecessor
+///   and not before the first `Day` of a `StartStart` predecessor. An
+///   `Activity` with an unplaced predecessor is left unplaced.
+/// * The `Work` of every `Skill` on a `Day`, spread evenly over the `Day`s of
+///   each `Activity`, fits inside of the `tactical_capacity`.
+/// * The `Work` of all `Skill`s together on a `Day` fits inside of the
+///   `tactical_total_capacity`, as a `Technician` with several `Skill`s counts
+///   towards each of them in the `tactical_capacity`.
+#[derive(Debug)]
+pub struct TacticalSolver<'a>
+{
+    tactical_instance: &'a TacticalInstance,
+    hours_per_day: Work,
+}
+
+impl<'a> TacticalSolver<'a>
+{
+    pub fn new(tactical_instance: &'a TacticalInstance) -> Self
+    {
+        Self {
+            tactical_instance,
+            hours_per_day: WorkingHoursRule::default().hours_per_day(),
+        }
+    }
+
+    pub fn with_hours_per_day(mut self, hours_per_day: Work) -> Self
+    {
+        self.hours_per_day = hours_per_day;
+        self
+    }
+
+    pub fn solve(&self) -> TacticalSolution
+    {
+        let mut solver_state = SolverState::new(self.tactical_instance, self.hours_per_day);
+
+        let mut work_orders = self.tactical_instance.tactical_work_orders.iter().collect::<Vec<_>>();
+        work_orders.sort_by_key(|(work_order_number, tactical_work_order)| (tactical_work_order.period, **work_order_number));
+        for (work_order_number, tactical_work_order) in work_orders {
+            solver_state.place_work_order(*work_order_number, tactical_work_order);
+        }
+
+        solver_state.tactical_solution
+    }
+}
+
+struct SolverState
+{
+    days: Vec<NaiveDate>,
+    remaining_capacity: Vec<HashMap<Skill, Work>>,
+    remaining_total_capacity: Vec<Work>,
+    hours_per_day: Work,
+    tactical_solution: TacticalSolution,
+}
+
+impl SolverState
+{
+    /// The `Work` of the `Activity`s that keep their `assigned_days` is
+    /// subtracted from the capacity up front.
+    fn new(tactical_instance: &TacticalInstance, hours_per_day: Work) -> Self
+    {
+        let mut solver_state = Self {
+            days: tactical_instance.tactical_days.clone(),
+            remaining_capacity: tactical_instance
+                .tactical_days
+                .iter()
+                .map(|day| tactical_instance.skill_capacity(day))
+                .collect(),
+            remaining_total_capacity: tactical_instance
+                .tactical_days
+                .iter()
+                .map(|day| tactical_instance.total_capacity(day))
+                .collect(),
+            hours_per_day,
+            tactical_solution: TacticalSolution::default(),
+        };
+
+        for (work_order_number, tactical_work_order) in &tactical_instance.tactical_work_orders {
+            for (activity_number, tactical_activity) in &tactical_work_order.activities {
+                if tactical_activity.assigned_days.is_empty() {
+                    continue;
+                }
+                let day_indices = tactical_activity
+                    .assigned_days
+                    .iter()
+                    .filter_map(|day| solver_state.days.iter().position(|other| other == day))
+                    .collect::<Vec<_>>();
+                solver_state.reserve(tactical_activity, &day_indices, tactical_activity.assigned_days.len());
+                solver_state.tactical_solution.place(
+                    *work_order_number,
+                    *activity_number,
+                    tactical_activity.assigned_days.iter().copied().collect(),
+                );
+            }
+        }
+        solver_state
+    }
+
+    /// The `Activity`s are placed in the order of their relations, so every
+    /// predecessor is decided before its successors.
+    fn place_work_order(&mut self, work_order_number: WorkOrderNumber, tactical_work_order: &TacticalWorkOrder)
+    {
+        let mut decided: HashMap<ActivityNumber, Option<(DayIndex, DayIndex)>> = HashMap::new();
+        for activity_number in tactical_work_order.activities.keys() {
+            if let Some(days) = self.tactical_solution.days(work_order_number, *activity_number) {
+                decided.insert(*activity_number, self.day_range(days));
+            }
+        }
+
+        while decided.len() < tactical_work_order.activities.len() {
+            let Some((activity_number, tactical_activity)) = tactical_work_order.activities.iter().find(|(activity_number, tactical_activity)| {
+                !decided.contains_key(activity_number)
+                    && tactical_activity
+                        .predecessors
+                        .iter()
+                        .all(|(predecessor, _)| decided.contains_key(predecessor) || !tactical_work_order.activities.contains_key(predecessor))
+            }) else {
+                // The remaining `Activity`s are part of a cycle
+                break;
+            };
+
+            let placement = self.earliest_placement(tactical_work_order, tactical_activity, &decided);
+            if let Some((first_day_index, last_day_index)) = placement {
+                let day_indices = (first_day_index..=last_day_index).collect::<Vec<_>>();
+                self.reserve(tactical_activity, &day_indices, day_indices.len());
+                self.tactical_solution.place(
+                    work_order_number,
+                    *activity_number,
+                    day_indices.iter().map(|&day_index| self.days[day_index]).collect(),
+                );
+            }
+            decided.insert(*activity_number, placement);
+        }
+    }
+
+    /// The first and last `Day` of the earliest feasible placement.
+    fn earliest_placement(
+        &self,
+        tactical_work_order: &TacticalWorkOrder,
+        tactical_activity: &TacticalActivity,
+        decided: &HashMap<ActivityNumber, Option<(DayIndex, DayIndex)>>,
+    ) -> Option<(DayIndex, DayIndex)>
+    {
+        let mut earliest_day_index = self.days.iter().position(|day| *day >= tactical_activity.earliest_start)?;
+        for (predecessor, activity_relation) in &tactical_activity.predecessors {
+            let Some(placement) = decided.get(predecessor) else {
+                continue;
+            };
+            let (first_day_index, last_day_index) = (*placement)?;
+            earliest_day_index = match activity_relation {
+                ActivityRelation::StartStart => earliest_day_index.max(first_day_index),
+                _ => earliest_day_index.max(last_day_index + 1),
+            };
+        }
+
+        let number_of_days = self.number_of_days(tactical_activity);
+        (earliest_day_index..self.days.len())
+            .map(|first_day_index| (first_day_index, first_day_index + number_of_days - 1))
+            .take_while(|(_, last_day_index)| *last_day_index < self.days.len())
+            .find(|&(first_day_index, last_day_index)| {
+                (first_day_index..=last_day_index).all(|day_index| {
+                    tactical_work_order.period.contains(self.days[day_index]) && self.fits(tactical_activity, day_index, number_of_days)
+                })
+            })
+    }
+
+    fn number_of_days(&self, tactical_activity: &TacticalActivity) -> usize
+    {
+        ((work_hours(tactical_activity.duration) / self.hours_per_day).ceil() as usize).max(1)
+    }
+
+    fn fits(&self, tactical_activity: &TacticalActivity, day_index: DayIndex, number_of_days: usize) -> bool
+    {
+        let total_work = tactical_activity.work_load.values().sum::<Work>();
+        total_work / number_of_days as Work <= self.remaining_total_capacity[day_index] + WORK_TOLERANCE
+            && tactical_activity.work_load.iter().all(|(skill, work)| {
+                work / number_of_days as Work <= self.remaining_capacity[day_index].get(skill).copied().unwrap_or(0.0) + WORK_TOLERANCE
+            })
+    }
+
+    fn reserve(&mut self, tactical_activity: &TacticalActivity, day_indices: &[DayIndex], number_of_days: usize)
+    {
+        for &day_index in day_indices {
+            for (skill, work) in &tactical_activity.work_load {
+                *self.remaining_capacity[day_index].entry(skill.clone()).or_insert(0.0) -= work / number_of_days as Work;
+                self.remaining_total_capacity[day_index] -= work / number_of_days as Work;
+            }
+        }
+    }
+
+    fn day_range(&self, days: &[NaiveDate]) -> Option<(DayIndex, DayIndex)>
+    {
+        let first_day_index = self.days.iter().position(|day| Some(day) == days.first())?;
+        let last_day_index = self.days.iter().position(|day| Some(day) == days.last())?;
+        Some((first_day_index, last_day_index))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use std::collections::BTreeMap;
+    use std::collections::BTreeSet;
+    use std::collections::HashMap;
+
+    use chrono::NaiveDate;
+    use chrono::TimeDelta;
+    use schedule_hypergraph::tactical_instance::TacticalActivity;
+    use schedule_hypergraph::tactical_instance::TacticalInstance;
+    use schedule_hypergraph::tactical_instance::TacticalWorkOrder;
+    use scheduling_environment::Period;
+    use scheduling_environment::technician::Skill;
+    use scheduling_environment::work_order::ActivityNumber;
+    use scheduling_environment::work_order::ActivityRelation;
+    use scheduling_environment::work_order::Work;
+
+    use super::TacticalSolver;
+
+    fn period() -> Period
+    {
+        Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap())
+    }
+
+    fn day(day: u32) -> NaiveDate
+    {
+        NaiveDate::from_ymd_opt(2025, 1, day).unwrap()
+    }
+
+    fn tactical_activity(work: Work, duration: i64, predecessors: Vec<(ActivityNumber, ActivityRelation)>) -> TacticalActivity
+    {
+        TacticalActivity {
+            work_load: HashMap::from([(Skill::new("MtnMech"), work)]),
+            number_of_people: 1,
+            duration: TimeDelta::hours(duration),
+            earliest_start: period().start_date(),
+            predecessors,
+            assigned_days: BTreeSet::new(),
+        }
+    }
+
+    /// One mechanic with 8 hours on every `Day` of the `Period`
+    fn tactical_instance(tactical_work_orders: Vec<(u64, Vec<(ActivityNumber, TacticalActivity)>)>) -> TacticalInstance
+    {
+        let tactical_days = period()
+            .start_date()
+            .iter_days()
+            .take_while(|day| *day <= period().end_date())
+            .collect::<Vec<_>>();
+
+        TacticalInstance {
+            tactical_capacity: tactical_days
+                .iter()
+                .map(|day| (*day, HashMap::from([(Skill::new("MtnMech"), 8.0)])))
+                .collect(),
+            tactical_total_capacity: tactical_days.iter().map(|day| (*day, 8.0)).collect(),
+            tactical_days,
+            tactical_work_orders: tactical_work_orders
+                .into_iter()
+                .map(|(work_order_number, activities)| {
+                    let tactical_work_order = TacticalWorkOrder {
+                        period: period(),
+                        activities: activities.into_iter().collect::<BTreeMap<_, _>>(),
+                    };
+                    (work_order_number, tactical_work_order)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_solve_respects_relations()
+    {
+        let tactical_instance = tactical_instance(vec![(
+            1,
+            vec![
+                (10, tactical_activity(16.0, 16, vec![])),
+                (20, tactical_activity(0.0, 8, vec![(10, ActivityRelation::StartStart)])),
+                (30, tactical_activity(8.0, 8, vec![(20, ActivityRelation::FinishStart)])),
+            ],
+        )]);
+
+        let tactical_solution = TacticalSolver::new(&tactical_instance).solve();
+
+        assert_eq!(tactical_solution.days(1, 10), Some([day(13), day(14)].as_slice()));
+        assert_eq!(tactical_solution.days(1, 20), Some([day(13)].as_slice()));
+        // The mechanic is busy with the first `Activity` on the 14th
+        assert_eq!(tactical_solution.days(1, 30), Some([day(15)].as_slice()));
+        assert!(tactical_solution.unplaced_activities(&tactical_instance).is_empty());
+    }
+
+    #[test]
+    fn test_solve_respects_capacity_and_period()
+    {
+        let mut fixed_activity = tactical_activity(8.0, 8, vec![]);
+        fixed_activity.assigned_days = BTreeSet::from([day(13)]);
+        let mut late_activity = tactical_activity(8.0, 8, vec![]);
+        late_activity.earliest_start = day(26);
+
+        let tactical_instance = tactical_instance(vec![
+            (1, vec![(10, fixed_activity)]),
+            (2, vec![(10, tactical_activity(8.0, 8, vec![]))]),
+            (
+                3,
+                vec![
+                    (10, late_activity),
+                    (20, tactical_activity(4.0, 4, vec![(10, ActivityRelation::FinishStart)])),
+                ],
+            ),
+            (4, vec![(10, tactical_activity(120.0, 120, vec![]))]),
+        ]);
+
+        let tactical_solution = TacticalSolver::new(&tactical_instance).solve();
+
+        assert_eq!(tactical_solution.days(1, 10), Some([day(13)].as_slice()));
+        assert_eq!(tactical_solution.days(2, 10), Some([day(14)].as_slice()));
+        assert_eq!(tactical_solution.days(3, 10), Some([day(26)].as_slice()));
+        // The successor would end up after the `Period` and the long
+        // `Activity` needs more `Day`s than are left.
+        assert_eq!(tactical_solution.unplaced_activities(&tactical_instance), vec![(3, 20), (4, 10)]);
+
+        for skill_load in tactical_solution.day_load(&tactical_instance).values() {
+            assert!(skill_load[&Skill::new("MtnMech")] <= 8.0);
+        }
+    }
+
+    #[test]
+    fn test_solve_respects_total_capacity()
+    {
+        let mut electrical_activity = tactical_activity(8.0, 8, vec![]);
+        electrical_activity.work_load = HashMap::from([(Skill::new("MtnElec"), 8.0)]);
+        let mut tactical_instance = tactical_instance(vec![
+            (1, vec![(10, tactical_activity(8.0, 8, vec![]))]),
+            (2, vec![(10, electrical_activity)]),
+        ]);
+        // The only `Technician` is both a mechanic and an electrician
+        for skill_capacity in tactical_instance.tactical_capacity.values_mut() {
+            skill_capacity.insert(Skill::new("MtnElec"), 8.0);
+        }
+
+        let tactical_solution = TacticalSolver::new(&tactical_instance).solve();
+
+        assert_eq!(tactical_solution.days(1, 10), Some([day(13)].as_slice()));
+        assert_eq!(tactical_solution.days(2, 10), Some([day(14)].as_slice()));
+        for skill_load in tactical_solution.day_load(&tactical_instance).values() {
+            assert!(skill_load.values().sum::<Work>() <= 8.0);
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use chrono::NaiveDate;
+use chrono::TimeDelta;
+use schedule_hypergraph::schedule_graph::ScheduleGraph;
+use schedule_hypergraph::schedule_graph::ScheduleGraphErrors;
+use scheduling_environment::Period;
+use scheduling_environment::system_clock::MockClock;
+use scheduling_environment::technician::Availability;
+use scheduling_environment::technician::Skill;
+use scheduling_environment::technician::SkillCatalog;
+use scheduling_environment::technician::Technician;
+use scheduling_environment::technician::WorkingHoursRule;
+use scheduling_environment::work_order::Activity;
+use scheduling_environment::work_order::WorkOrder;
+use tactical_algorithm::tactical_solution::ActivityMove;
+use tactical_algorithm::tactical_solver::TacticalSolver;
+
+#[test]
+fn test_apply_tactical_solution()
+{
+    let system_clock = MockClock::new(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap());
+    let mut schedule_graph = ScheduleGraph::new().with_system_clock(system_clock);
+
+    let period = Period::from_start_date(NaiveDate::from_ymd_opt(2025, 1, 13).unwrap());
+    schedule_graph.add_skill_catalog(&SkillCatalog::from_iter([Skill::new("MtnMech")]));
+    schedule_graph.add_period(period).unwrap();
+
+    let work_order = WorkOrder::new(
+        1122334455,
+        period.start_date(),
+        vec![
+            Activity::new(10, 1, Skill::new("MtnMech"), TimeDelta::hours(16), TimeDelta::hours(16)),
+            Activity::new(20, 1, Skill::new("MtnMech"), TimeDelta::hours(8), TimeDelta::hours(8)),
+        ],
+    )
+    .unwrap();
+    schedule_graph.add_work_order(&work_order).unwrap();
+    schedule_graph.add_assignment_work_order_period(&1122334455, &period).unwrap();
+
+    let start = period.start_date().and_hms_opt(7, 0, 0).unwrap();
+    let availability = Availability::new(start, start + TimeDelta::days(4));
+    let technician = Technician::builder(1001)
+        .add_availability(availability.start(), availability.end())
+        .unwrap()
+        .add_skill(Skill::new("MtnMech"))
+        .build();
+    schedule_graph.add_technician(technician, availability).unwrap();
+
+    let tactical_instance = schedule_graph
+        .derive_tactical_instance(&schedule_graph.upcoming_periods(), &WorkingHoursRule::default())
+        .unwrap();
+    let tactical_solution = TacticalSolver::new(&tactical_instance).solve();
+    assert!(tactical_solution.unplaced_activities(&tactical_instance).is_empty());
+
+    let days = period.start_date().iter_days().take(3).collect::<Vec<_>>();
+    let applied_tactical_solution = tactical_solution.apply(&mut schedule_graph).unwrap();
+    assert_eq!(
+        applied_tactical_solution.moved,
+        vec![
+            ActivityMove {
+                work_order_number: 1122334455,
+                activity_number: 10,
+                from: vec![],
+                to: days[..2].to_vec(),
+            },
+            ActivityMove {
+                work_order_number: 1122334455,
+                activity_number: 20,
+                from: vec![],
+                to: days[2..].to_vec(),
+            },
+        ]
+    );
+    assert_eq!(schedule_graph.activity_planned_days(&1122334455, 10).unwrap(), days[..2].to_vec());
+    assert_eq!(schedule_graph.activity_planned_days(&1122334455, 20).unwrap(), days[2..].to_vec());
+
+    // The planned days become a part of the next `TacticalInstance`
+    let tactical_instance = schedule_graph
+        .derive_tactical_instance(&schedule_graph.upcoming_periods(), &WorkingHoursRule::default())
+        .unwrap();
+    assert_eq!(TacticalSolver::new(&tactical_instance).solve(), tactical_solution);
+    assert!(tactical_solution.apply(&mut schedule_graph).unwrap().moved.is_empty());
+
+    // Frozen days are not moved
+    schedule_graph.freeze_period(&period).unwrap();
+    let mut moved_solution = tactical_solution.clone();
+    moved_solution.place(1122334455, 20, vec![days[2] + TimeDelta::days(1)]);
+    let applied_tactical_solution = moved_solution.apply(&mut schedule_graph).unwrap();
+    assert!(applied_tactical_solution.moved.is_empty());
+    assert_eq!(applied_tactical_solution.locked, vec![(1122334455, 20)]);
+
+    moved_solution.place(1122334455, 20, vec![NaiveDate::from_ymd_opt(2025, 3, 3).unwrap()]);
+    assert_eq!(moved_solution.apply(&mut schedule_graph), Err(ScheduleGraphErrors::DayMissing));
+}
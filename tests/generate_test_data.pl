@@ -10,9 +10,12 @@ my $NUM_WORK_ORDERS = 1000;
 my $NUM_TECHNICIANS = 100;
 my $BASE_DATE = "2025-01-13";  # Monday, start of first period
 
-# Skills available
+# Skills used by the generated work orders and technicians
 my @SKILLS = ("MtnMech", "MtnElec");
 
+# Skill catalog: every craft known to the system
+my @SKILL_CATALOG = ("MtnMech", "MtnElec", "MtnInst", "MtnScaf", "MtnInsu", "MtnRope", "MtnCran");
+
 # Parse base date
 my ($base_year, $base_month, $base_day) = split(/-/, $BASE_DATE);
 
@@ -43,7 +46,8 @@ for my $i (0 .. $NUM_PERIODS - 1) {
 # {
 #   "work_order_number": u64 (10 digits),
 #   "basic_start_date": "YYYY-MM-DD",
-#   "activities": [{ "activity_number": u64, "number_of_people": u64, "resource": "MtnMech"|"MtnElec" }]
+#   "activities": [{ "activity_number": u64, "work": hours, "duration": hours,
+#                    "requirements": [{ "skill": "MtnMech"|"MtnElec", "number_of_people": u64 }] }]
 # }
 my @work_orders = ();
 for my $i (0 .. $NUM_WORK_ORDERS - 1) {
@@ -62,10 +66,12 @@ for my $i (0 .. $NUM_WORK_ORDERS - 1) {
         my $activity_number = ($j + 1) * 10;  # 10, 20, 30...
         my $number_of_people = 1 + int(rand(3));  # 1-3 people
         my $skill = $SKILLS[int(rand(scalar @SKILLS))];
+        my $duration = 1 + int(rand(8));  # 1-8 hours
         push @activities, {
             activity_number => $activity_number,
-            number_of_people => $number_of_people,
-            resource => $skill,
+            requirements => [{ skill => $skill, number_of_people => $number_of_people }],
+            work => $number_of_people * $duration,
+            duration => $duration,
         };
     }
 
@@ -122,6 +128,11 @@ for my $i (0 .. $NUM_TECHNICIANS - 1) {
 # Write JSON files
 my $json = JSON::PP->new->pretty->canonical;
 
+# Write skills.json
+open(my $sf, '>', 'tests/skills.json') or die "Cannot open skills.json: $!";
+print $sf $json->encode([sort @SKILL_CATALOG]);
+close($sf);
+
 # Write periods.json
 open(my $pf, '>', 'tests/periods.json') or die "Cannot open periods.json: $!";
 print $pf $json->encode(\@periods);
@@ -138,6 +149,7 @@ print $tf $json->encode(\@technicians);
 close($tf);
 
 print "Generated:\n";
+print "  - tests/skills.json (" . scalar(@SKILL_CATALOG) . " skills)\n";
 print "  - tests/periods.json ($NUM_PERIODS periods)\n";
 print "  - tests/work_orders.json ($NUM_WORK_ORDERS work orders)\n";
 print "  - tests/technicians.json ($NUM_TECHNICIANS technicians)\n";